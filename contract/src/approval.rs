@@ -5,8 +5,8 @@ const GAS_FOR_NFT_APPROVE: Gas = Gas(10_000_000_000_000);
 const NO_DEPOSIT: Balance = 0;
 
 pub trait NonFungibleTokenCore {
-    //approve an account ID to transfer a token on your behalf
-    fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>);
+    //approve an account ID to transfer a token on your behalf, optionally until the passed in time in milliseconds
+    fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>, expires_at: Option<u64>);
 
     //check if the passed in account has access to approve the token ID
 	fn nft_is_approved(
@@ -40,7 +40,7 @@ impl NonFungibleTokenCore for Contract {
 
     //allow a specific account ID to approve a token on your behalf
     #[payable]
-    fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>) {
+    fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>, expires_at: Option<u64>) {
         /*
             assert at least one yocto for security reasons - this will cause a redirect to the NEAR wallet.
             The user needs to attach enough to pay for storage on the contract
@@ -57,16 +57,23 @@ impl NonFungibleTokenCore for Contract {
             "Predecessor must be the token owner."
         );
 
+        //soulbound tokens can't be approved since they can't be transferred
+        assert!(!self.is_soulbound(&token_id), "Token is soulbound and cannot be approved");
+        //locked tokens can't be approved until the lock runs out
+        if let Some(locked_until) = self.locked_until(&token_id) {
+            env::panic_str(&format!("Token is locked for transfers until {}", locked_until));
+        }
+
         //get the next approval ID if we need a new approval
         let approval_id: u64 = token.next_approval_id;
 
         //check if the account has been approved already for this token
-        let is_new_approval = token
+        let previous_approval_id = token
             .approved_account_ids
             //insert returns none if the key was not present.  
-            .insert(account_id.clone(), approval_id)
-            //if the key was not present, .is_none() will return true so it is a new approval.
-            .is_none();
+            .insert(account_id.clone(), approval_id);
+        //if the key was not present, .is_none() will return true so it is a new approval.
+        let is_new_approval = previous_approval_id.is_none();
 
         //if it was a new approval, we need to calculate how much storage is being used to add the account.
         let storage_used = if is_new_approval {
@@ -76,13 +83,24 @@ impl NonFungibleTokenCore for Contract {
             0
         };
 
+        //the approval replaces the previous one along with its expiry
+        let initial_storage_usage = env::storage_usage();
+        if let Some(previous_approval_id) = previous_approval_id {
+            self.approval_expiries.remove(&(token_id.clone(), previous_approval_id));
+        }
+        if let Some(expires_at) = expires_at {
+            assert!(expires_at > env::block_timestamp() / 1000000, "Approval must expire in the future");
+            self.approval_expiries.insert(&(token_id.clone(), approval_id), &expires_at);
+        }
+        let storage_used = (storage_used + env::storage_usage()).saturating_sub(initial_storage_usage);
+
         //increment the token's next approval ID by 1
         token.next_approval_id += 1;
         //insert the token back into the tokens_by_id collection
         self.tokens_by_id.insert(&token_id, &token);
 
-        //refund any excess storage attached by the user. If the user didn't attach enough, panic. 
-        refund_deposit(storage_used);
+        //refund any excess storage attached by the user. If the user didn't attach enough, draw the rest from their storage balance.
+        self.internal_pay_storage(&token.owner_id, storage_used, 0);
 
         //if some message was passed into the function, we initiate a cross contract call on the
         //account we're giving access to. 
@@ -110,11 +128,16 @@ impl NonFungibleTokenCore for Contract {
         //get the token object from the token_id
         let token = self.tokens_by_id.get(&token_id).expect("No token");
 
+        //operators can transfer all the tokens of the owner
+        if approval_id.is_none() && self.is_operator(&token.owner_id, &approved_account_id) {
+            return true;
+        }
+
         //get the approval number for the passed in account ID
 		let approval = token.approved_account_ids.get(&approved_account_id);
 
-        //if there was some approval ID found for the account ID
-        if let Some(approval) = approval {
+        //if there was some approval ID found for the account ID that didn't expire
+        if let Some(approval) = approval.filter(|approval| !self.is_approval_expired(&token_id, **approval)) {
             //if a specific approval_id was passed into the function
 			if let Some(approval_id) = approval_id {
                 //return if the approval ID passed in matches the actual approval ID for the account
@@ -142,13 +165,13 @@ impl NonFungibleTokenCore for Contract {
         assert_eq!(&predecessor_account_id, &token.owner_id);
 
         //if the account ID was in the token's approval, we remove it and the if statement logic executes
-        if token
+        if let Some(approval_id) = token
             .approved_account_ids
             .remove(&account_id)
-            .is_some()
         {
-            //refund the funds released by removing the approved_account_id to the caller of the function
-            refund_approved_account_ids_iter(predecessor_account_id, [account_id].iter());
+            //refund the funds released by removing the approved_account_id and its expiry to the caller of the function
+            let revoked = HashMap::from([(account_id, approval_id)]);
+            self.internal_refund_approvals(predecessor_account_id, &token_id, &revoked);
 
             //insert the token back into the tokens_by_id collection with the account_id removed from the approval list
             self.tokens_by_id.insert(&token_id, &token);
@@ -169,12 +192,22 @@ impl NonFungibleTokenCore for Contract {
 
         //only revoke if the approved account IDs for the token is not empty
         if !token.approved_account_ids.is_empty() {
-            //refund the approved account IDs to the caller of the function
-            refund_approved_account_ids(predecessor_account_id, &token.approved_account_ids);
+            //refund the approved account IDs and their expiries to the caller of the function
+            self.internal_refund_approvals(predecessor_account_id, &token_id, &token.approved_account_ids);
             //clear the approved account IDs
             token.approved_account_ids.clear();
             //insert the token back into the tokens_by_id collection with the approved account IDs cleared
             self.tokens_by_id.insert(&token_id, &token);
         }
     }
-}
\ No newline at end of file
+}
+
+impl Contract {
+    //check if an approval of a token has expired. Approvals without an expiry never expire.
+    pub(crate) fn is_approval_expired(&self, token_id: &TokenId, approval_id: u64) -> bool {
+        self.approval_expiries
+            .get(&(token_id.clone(), approval_id))
+            .map(|expires_at| env::block_timestamp() / 1000000 >= expires_at)
+            .unwrap_or(false)
+    }
+}
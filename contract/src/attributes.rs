@@ -0,0 +1,121 @@
+use crate::*;
+
+//limits on the stats a token can carry so the extra field stays small
+pub(crate) const MAX_STATS: usize = 16;
+const MAX_STAT_NAME_LEN: usize = 32;
+
+//what kind of in-game item a token is
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum TokenCategory {
+    Pet,
+    Avatar,
+    Consumable,
+    EggShard,
+}
+
+//how rare a token is, from most to least common
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+//the on-chain game attributes of a token. These are also stored as JSON in the token's `extra` metadata field.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenAttributes {
+    pub category: TokenCategory, // ex. Pet
+    pub rarity: Rarity, // ex. Legendary
+    pub level: u32, // current level of the item, starting at 1
+    pub stats: HashMap<String, u32>, // ex. {"attack": 10, "defense": 7}
+}
+
+#[near_bindgen]
+impl Contract {
+    //set the game attributes for a token. Caller needs to attach enough to cover any extra storage.
+    #[payable]
+    pub fn nft_set_attributes(&mut self, token_id: TokenId, attributes: TokenAttributes) {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+        //make sure that the caller is allowed to update metadata
+        self.assert_metadata_updater();
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        self.internal_set_attributes(&token_id, &attributes);
+
+        //calculate the extra storage used by the update (if the attributes shrunk, nothing needs to be paid)
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+
+        self.internal_log_metadata_update(vec![token_id]);
+    }
+
+    //get the game attributes for a specific token ID
+    pub fn nft_attributes(&self, token_id: TokenId) -> Option<TokenAttributes> {
+        self.attributes_by_id.get(&token_id)
+    }
+}
+
+impl Contract {
+    //store the attributes for a token and mirror them into the token's extra field
+    pub(crate) fn internal_set_attributes(&mut self, token_id: &TokenId, attributes: &TokenAttributes) {
+        assert_valid_attributes(attributes);
+
+        let mut metadata = self.token_metadata_by_id.get(token_id).expect("No token");
+
+        //the extra field always holds the JSON version of the attributes
+        metadata.extra = Some(near_sdk::serde_json::to_string(attributes).unwrap());
+        //set the time of the update in milliseconds
+        metadata.updated_at = Some(env::block_timestamp() / 1000000);
+
+        self.token_metadata_by_id.insert(token_id, &metadata);
+        self.attributes_by_id.insert(token_id, attributes);
+    }
+}
+
+//make sure the stats don't blow up the size of the metadata
+pub(crate) fn assert_valid_attributes(attributes: &TokenAttributes) {
+    assert!(attributes.stats.len() <= MAX_STATS, "Cannot have more than {} stats", MAX_STATS);
+    for name in attributes.stats.keys() {
+        assert!(name.len() <= MAX_STAT_NAME_LEN, "Stat name cannot be longer than {} bytes", MAX_STAT_NAME_LEN);
+    }
+}
+
+//roll a rarity. Out of 10000: 50% common, 25% uncommon, 15% rare, 8% epic and 2% legendary.
+pub(crate) fn random_rarity(salt: &[u8]) -> Rarity {
+    match random_u64(salt) % 10_000 {
+        0..=4_999 => Rarity::Common,
+        5_000..=7_499 => Rarity::Uncommon,
+        7_500..=8_999 => Rarity::Rare,
+        9_000..=9_799 => Rarity::Epic,
+        _ => Rarity::Legendary,
+    }
+}
+
+//randomize the rarity of a template and scale each of its stats by a random factor between 80% and 120%
+pub(crate) fn randomize_attributes(template: &TokenAttributes, salt: &[u8]) -> TokenAttributes {
+    let stats = template
+        .stats
+        .iter()
+        .map(|(name, value)| {
+            let factor = 80 + random_u64(&[salt, name.as_bytes()].concat()) % 41;
+            (name.clone(), (*value as u64 * factor / 100) as u32)
+        })
+        .collect();
+
+    TokenAttributes {
+        category: template.category,
+        rarity: random_rarity(salt),
+        level: template.level,
+        stats,
+    }
+}
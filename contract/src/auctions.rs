@@ -0,0 +1,330 @@
+use crate::*;
+
+//how auctions determine the price of a token
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AuctionKind {
+    /*
+        the highest bid when the auction ends wins. Bids need to be at least the reserve price and beat
+        the previous bid by the min increment. A bid less than `extension` milliseconds before the end
+        extends the auction so it ends `extension` milliseconds after that bid.
+    */
+    English {
+        reserve_price: U128,
+        min_increment: U128,
+        extension: u64,
+    },
+    //the price decays linearly from the start price to the end price and the first bid wins
+    Dutch {
+        start_price: U128,
+        end_price: U128,
+    },
+}
+
+//a token that is being auctioned on the contract
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Auction {
+    //owner of the token when the auction started
+    pub seller_id: AccountId,
+    pub kind: AuctionKind,
+    //when the auction started, Unix epoch in milliseconds
+    pub starts_at: u64,
+    //when the auction ends, Unix epoch in milliseconds
+    pub ends_at: u64,
+    //the highest bidder and the yoctoNEAR they escrowed
+    pub highest_bid: Option<(AccountId, Balance)>,
+    //yoctoNEAR the seller payed for the storage of the auction
+    pub storage_deposit: Balance,
+}
+
+//The Json auction is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonAuction {
+    //token ID
+    pub token_id: TokenId,
+    //owner of the auctioned token
+    pub seller_id: AccountId,
+    pub kind: AuctionKind,
+    //when the auction started, Unix epoch in milliseconds
+    pub starts_at: u64,
+    //when the auction ends, Unix epoch in milliseconds
+    pub ends_at: u64,
+    //the highest bidder so far
+    pub highest_bidder: Option<AccountId>,
+    //the highest bid so far
+    pub highest_bid: Option<U128>,
+    //the lowest bid that is currently accepted
+    pub min_bid: U128,
+}
+
+#[near_bindgen]
+impl Contract {
+    //start an English auction for a token of the caller. The caller needs to attach enough to cover the storage.
+    #[payable]
+    pub fn start_english_auction(
+        &mut self,
+        token_id: TokenId,
+        reserve_price: U128,
+        min_increment: U128,
+        ends_at: u64,
+        extension: u64,
+    ) {
+        assert!(u128::from(min_increment) > 0, "Min increment must be greater than 0");
+        self.internal_start_auction(token_id, ends_at, AuctionKind::English {
+            reserve_price,
+            min_increment,
+            extension,
+        });
+    }
+
+    //start a Dutch auction for a token of the caller. The caller needs to attach enough to cover the storage.
+    #[payable]
+    pub fn start_dutch_auction(&mut self, token_id: TokenId, start_price: U128, end_price: U128, ends_at: u64) {
+        assert!(
+            u128::from(start_price) > u128::from(end_price),
+            "Start price must be greater than the end price"
+        );
+        self.internal_start_auction(token_id, ends_at, AuctionKind::Dutch {
+            start_price,
+            end_price,
+        });
+    }
+
+    /*
+        bid the attached deposit on an auctioned token. For English auctions the previous highest bidder is refunded.
+        Dutch auctions are won by the first bid of at least the current price and are settled right away.
+    */
+    #[payable]
+    pub fn bid(&mut self, token_id: TokenId) {
+        let bidder_id = env::predecessor_account_id();
+        let mut amount = env::attached_deposit();
+        let mut auction = self.auctions_by_id.get(&token_id).expect("Token is not being auctioned");
+        let now = env::block_timestamp() / 1000000;
+
+        assert_ne!(bidder_id, auction.seller_id, "The seller can't bid on their own auction");
+        assert!(now < auction.ends_at, "Auction has ended");
+        let min_bid = auction_min_bid(&auction, now);
+        assert!(amount >= min_bid, "Bid must be at least {} yoctoNEAR", min_bid);
+
+        //Dutch auctions are won at the current price and anything above it is refunded
+        if let AuctionKind::Dutch { .. } = auction.kind {
+            if amount > min_bid {
+                Promise::new(bidder_id.clone()).transfer(amount - min_bid);
+            }
+            amount = min_bid;
+        }
+
+        //refund the previous highest bidder
+        if let Some((previous_bidder_id, previous_amount)) = auction.highest_bid.take() {
+            Promise::new(previous_bidder_id).transfer(previous_amount);
+        }
+        auction.highest_bid = Some((bidder_id.clone(), amount));
+
+        match auction.kind {
+            AuctionKind::English { extension, .. } => {
+                //bids right before the end give others time to respond
+                if auction.ends_at - now < extension {
+                    auction.ends_at = now + extension;
+                }
+            }
+            AuctionKind::Dutch { .. } => {
+                //the first bid wins, so the auction is over
+                auction.ends_at = now;
+            }
+        }
+
+        self.internal_log_market(EventLogVariant::NftBid(vec![NftBidLog {
+            token_id: token_id.clone(),
+            bidder_id: bidder_id.to_string(),
+            amount: amount.to_string(),
+            ends_at: auction.ends_at,
+        }]));
+
+        if let AuctionKind::Dutch { .. } = auction.kind {
+            self.internal_settle_auction(&token_id, auction);
+        } else {
+            self.auctions_by_id.insert(&token_id, &auction);
+        }
+    }
+
+    /*
+        settle an auction that has ended. Anyone can call this. The token goes to the highest bidder and the
+        bid is payed out to the seller and royalty receivers, minus the market fee. Without bids the token stays with the seller.
+    */
+    pub fn settle_auction(&mut self, token_id: TokenId) {
+        let auction = self.auctions_by_id.get(&token_id).expect("Token is not being auctioned");
+        assert!(
+            env::block_timestamp() / 1000000 >= auction.ends_at,
+            "Auction is still running until {}",
+            auction.ends_at
+        );
+        self.internal_settle_auction(&token_id, auction);
+    }
+
+    //cancel an auction of the caller that didn't receive any bids yet
+    #[payable]
+    pub fn cancel_auction(&mut self, token_id: TokenId) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+
+        let auction = self.auctions_by_id.get(&token_id).expect("Token is not being auctioned");
+        assert_eq!(
+            auction.seller_id,
+            env::predecessor_account_id(),
+            "Only the seller can cancel the auction"
+        );
+        assert!(auction.highest_bid.is_none(), "Auctions with bids can't be cancelled");
+
+        self.auctions_by_id.remove(&token_id);
+        Promise::new(auction.seller_id).transfer(auction.storage_deposit);
+    }
+
+    //get the auction for a specific token ID
+    pub fn get_auction(&self, token_id: TokenId) -> Option<JsonAuction> {
+        let now = env::block_timestamp() / 1000000;
+        self.auctions_by_id.get(&token_id).map(|auction| JsonAuction {
+            min_bid: U128(auction_min_bid(&auction, now)),
+            token_id,
+            seller_id: auction.seller_id,
+            kind: auction.kind,
+            starts_at: auction.starts_at,
+            ends_at: auction.ends_at,
+            highest_bidder: auction.highest_bid.as_ref().map(|(bidder_id, _)| bidder_id.clone()),
+            highest_bid: auction.highest_bid.map(|(_, amount)| U128(amount)),
+        })
+    }
+
+    //Query for the auctions on the contract
+    pub fn get_auctions(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonAuction> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        //iterate through the auctioned token IDs
+        self.auctions_by_id.keys()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements in the vector. If we didn't specify a limit, use 50
+            .take(limit.unwrap_or(50) as usize)
+            //we'll map the token IDs into Json Auctions
+            .filter_map(|token_id| self.get_auction(token_id))
+            //since we turned the keys into an iterator, we need to turn it back into a vector to return
+            .collect()
+    }
+}
+
+impl Contract {
+    //check if a token is being auctioned. Auctioned tokens can't be transferred, staked or burned until the auction is settled.
+    pub(crate) fn is_auctioned(&self, token_id: &TokenId) -> bool {
+        self.auctions_by_id.get(token_id).is_some()
+    }
+
+    //start an auction for a token of the caller
+    fn internal_start_auction(&mut self, token_id: TokenId, ends_at: u64, kind: AuctionKind) {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+
+        let seller_id = env::predecessor_account_id();
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        assert_eq!(token.owner_id, seller_id, "Predecessor must be the token owner.");
+        let now = env::block_timestamp() / 1000000;
+        assert!(ends_at > now, "Auction must end in the future");
+
+        //tokens that can't be transferred can't be auctioned either
+        assert!(!self.is_auctioned(&token_id), "Token is already being auctioned");
+        assert!(self.listings_by_id.get(&token_id).is_none(), "Token is listed on the market");
+        assert!(!self.is_soulbound(&token_id), "Token is soulbound and cannot be auctioned");
+        assert!(!self.is_staked(&token_id), "Token is staked");
+        assert!(!self.is_expired(&token_id), "Token has expired");
+        if let Some(locked_until) = self.locked_until(&token_id) {
+            env::panic_str(&format!("Token is locked for transfers until {}", locked_until));
+        }
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let mut auction = Auction {
+            seller_id: seller_id.clone(),
+            kind: kind.clone(),
+            starts_at: now,
+            ends_at,
+            highest_bid: None,
+            storage_deposit: 0,
+        };
+        self.auctions_by_id.insert(&token_id, &auction);
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+        auction.storage_deposit = env::storage_byte_cost() * Balance::from(required_storage_in_bytes);
+        self.auctions_by_id.insert(&token_id, &auction);
+
+        self.internal_log_market(EventLogVariant::NftAuctionStart(vec![NftAuctionStartLog {
+            token_id,
+            seller_id: seller_id.to_string(),
+            kind,
+            ends_at,
+        }]));
+
+        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    //transfer the token to the highest bidder and pay out the bid
+    fn internal_settle_auction(&mut self, token_id: &TokenId, auction: Auction) {
+        self.auctions_by_id.remove(token_id);
+        Promise::new(auction.seller_id.clone()).transfer(auction.storage_deposit);
+
+        let (winner_id, amount) = match auction.highest_bid {
+            Some(highest_bid) => highest_bid,
+            //without bids the token simply stays with the seller
+            None => return,
+        };
+
+        //transfer the token to the winner. The checks of a regular transfer were done when the auction started.
+        let previous_token = self.tokens_by_id.get(token_id).expect("No token");
+        self.internal_move_token(
+            &previous_token,
+            &winner_id,
+            token_id,
+            None,
+            Some("Won at auction".to_string()),
+        );
+
+        //refund the previous token owner for the storage used up by the previous approved account IDs
+        if !previous_token.approved_account_ids.is_empty() {
+            self.internal_refund_approvals(
+                previous_token.owner_id.clone(),
+                token_id,
+                &previous_token.approved_account_ids,
+            );
+        }
+
+        //pay out the seller, the royalty receivers and the market
+        let market_fee = self.internal_pay_out_sale(&previous_token, amount);
+
+        self.internal_log_market(EventLogVariant::NftSale(vec![NftSaleLog {
+            token_id: token_id.to_string(),
+            seller_id: previous_token.owner_id.to_string(),
+            buyer_id: winner_id.to_string(),
+            price: amount.to_string(),
+            market_fee: market_fee.to_string(),
+            ft_contract_id: None,
+        }]));
+    }
+}
+
+//get the lowest bid an auction accepts at the passed in time
+fn auction_min_bid(auction: &Auction, now: u64) -> Balance {
+    match &auction.kind {
+        AuctionKind::English { reserve_price, min_increment, .. } => match &auction.highest_bid {
+            Some((_, amount)) => amount + min_increment.0,
+            None => reserve_price.0,
+        },
+        AuctionKind::Dutch { start_price, end_price } => {
+            //the price decays linearly over the duration of the auction
+            let duration = Balance::from(auction.ends_at - auction.starts_at);
+            let elapsed = Balance::from(now.min(auction.ends_at) - auction.starts_at);
+            start_price.0 - (start_price.0 - end_price.0) * elapsed / duration
+        }
+    }
+}
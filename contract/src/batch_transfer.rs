@@ -0,0 +1,90 @@
+use crate::*;
+use near_sdk::Gas;
+
+//GAS used up by transferring a single token of a batch
+const GAS_PER_BATCH_TRANSFER: Gas = Gas(8_000_000_000_000);
+//maximum number of tokens that can be transferred in a single batch (GAS limits this)
+const MAX_BATCH_TRANSFER: usize = 30;
+
+#[near_bindgen]
+impl Contract {
+    /*
+        transfer many tokens at once. Every transfer is a receiver ID, token ID and optional approval ID and has the same
+        rules as nft_transfer. If one of the transfers fails, none of them happen. The transfers are logged in a single
+        event, grouped by old and new owner.
+    */
+    #[payable]
+    pub fn nft_batch_transfer(&mut self, transfers: Vec<(AccountId, TokenId, Option<u64>)>) {
+        //assert that the user attached exactly 1 yoctoNEAR. This is for security and so that the user will be redirected to the NEAR wallet.
+        assert_one_yocto();
+        assert!(
+            !transfers.is_empty() && transfers.len() <= MAX_BATCH_TRANSFER,
+            "Can transfer between 1 and {} tokens at once",
+            MAX_BATCH_TRANSFER
+        );
+        let required_gas = Gas(GAS_PER_BATCH_TRANSFER.0 * transfers.len() as u64);
+        assert!(
+            env::prepaid_gas() >= required_gas,
+            "You cannot attach less than {:?} Gas to transfer {} tokens",
+            required_gas,
+            transfers.len()
+        );
+
+        //get the sender to transfer the tokens from the sender to the receivers
+        let sender_id = env::predecessor_account_id();
+
+        let mut transfer_logs: Vec<NftTransferLog> = vec![];
+        //storage released by the approved account IDs, per previous owner
+        let mut storage_released: HashMap<AccountId, u64> = HashMap::new();
+        for (receiver_id, token_id, approval_id) in transfers {
+            let previous_token = self.internal_transfer_unlogged(&sender_id, &receiver_id, &token_id, approval_id);
+
+            let expiries_released = self.internal_remove_approval_expiries(&token_id, &previous_token.approved_account_ids);
+            *storage_released.entry(previous_token.owner_id.clone()).or_default() += expiries_released
+                + previous_token
+                    .approved_account_ids
+                    .keys()
+                    .map(bytes_for_approved_account_id)
+                    .sum::<u64>();
+
+            //the sender is the authorized ID if an approval ID was provided
+            let authorized_id = approval_id.map(|_| sender_id.to_string());
+            let old_owner_id = previous_token.owner_id.to_string();
+            let new_owner_id = receiver_id.to_string();
+
+            //tokens with the same old and new owner are logged together
+            match transfer_logs.iter_mut().find(|log| {
+                log.authorized_id == authorized_id && log.old_owner_id == old_owner_id && log.new_owner_id == new_owner_id
+            }) {
+                Some(log) => log.token_ids.push(token_id),
+                None => transfer_logs.push(NftTransferLog {
+                    authorized_id,
+                    old_owner_id,
+                    new_owner_id,
+                    token_ids: vec![token_id],
+                    memo: None,
+                }),
+            }
+        }
+
+        //we refund the owners for releasing the storage used up by the approved account IDs
+        for (owner_id, storage_released) in storage_released {
+            if storage_released > 0 {
+                Promise::new(owner_id).transfer(Balance::from(storage_released) * env::storage_byte_cost());
+            }
+        }
+
+        // Construct the transfer log as per the events standard.
+        let nft_transfer_log: EventLog = EventLog {
+            // Standard name ("nep171").
+            standard: NFT_STANDARD_NAME.to_string(),
+            // Version of the standard ("nft-1.0.0").
+            version: NFT_METADATA_SPEC.to_string(),
+            // The data related with the event stored in a vector.
+            event: EventLogVariant::NftTransfer(transfer_logs),
+        };
+
+        // Log the serialized json.
+        env::log_str(&nft_transfer_log.to_string());
+    }
+}
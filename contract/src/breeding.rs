@@ -0,0 +1,190 @@
+use crate::*;
+
+//how far up the family tree nft_lineage is allowed to go (GAS limits this)
+const MAX_LINEAGE_DEPTH: u32 = 5;
+//chance out of 10000 for a child to roll a new rarity instead of inheriting one from its parents
+const RARITY_MUTATION_CHANCE: u64 = 1_000;
+
+//how pets are bred on the contract
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BreedingConfig {
+    //token type every child is minted as
+    pub child_token_type_id: TokenTypeId,
+    //time in milliseconds a parent has to wait before it can breed again
+    pub cooldown: u64,
+}
+
+//the parents of a bred token
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Lineage {
+    pub parents: (TokenId, TokenId),
+    pub generation: u32,
+}
+
+//The Json lineage is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonLineage {
+    //token ID
+    pub token_id: TokenId,
+    //0 for tokens that weren't bred, otherwise one more than the highest generation of the parents
+    pub generation: u32,
+    //the parents of the token if it was bred
+    pub parents: Option<(TokenId, TokenId)>,
+}
+
+#[near_bindgen]
+impl Contract {
+    //set how pets are bred. Breeding is disabled until this is called.
+    pub fn set_breeding_config(&mut self, config: BreedingConfig) {
+        self.assert_owner();
+        assert!(self.token_types_by_id.get(&config.child_token_type_id).is_some(), "No token type");
+        self.breeding_config = Some(config);
+    }
+
+    //get how pets are bred
+    pub fn get_breeding_config(&self) -> Option<BreedingConfig> {
+        self.breeding_config.clone()
+    }
+
+    /*
+        breed two pets owned by the caller and mint the child to the caller. Both parents go on cooldown.
+        The caller needs to attach enough to cover the storage for the child.
+    */
+    #[payable]
+    pub fn breed(&mut self, parent_a: TokenId, parent_b: TokenId) -> TokenId {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+
+        let config = self.breeding_config.clone().expect("Breeding is disabled");
+        let owner_id = env::predecessor_account_id();
+        assert_ne!(parent_a, parent_b, "A pet can't breed with itself");
+
+        let now = env::block_timestamp() / 1000000;
+        let attributes_a = self.internal_assert_can_breed(&owner_id, &parent_a, now);
+        let attributes_b = self.internal_assert_can_breed(&owner_id, &parent_b, now);
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        //both parents have to wait before they can breed again
+        self.breeding_cooldowns.insert(&parent_a, &(now + config.cooldown));
+        self.breeding_cooldowns.insert(&parent_b, &(now + config.cooldown));
+
+        //mint the child with attributes derived from its parents
+        //the number of children minted so far makes the randomness differ when the same pair breeds again in a block
+        let minted = self.token_types_by_id.get(&config.child_token_type_id).expect("No token type").minted;
+        let salt = [parent_a.as_bytes(), parent_b.as_bytes(), &minted.to_le_bytes()].concat();
+        let child_attributes = child_attributes(&attributes_a, &attributes_b, &salt);
+        let token_id = self.internal_mint_typed(&config.child_token_type_id, &owner_id, Some(child_attributes));
+
+        //the child is one generation after the youngest parent
+        let generation = self.internal_generation(&parent_a).max(self.internal_generation(&parent_b)) + 1;
+        self.lineage_by_id.insert(&token_id, &Lineage {
+            parents: (parent_a, parent_b),
+            generation,
+        });
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+
+        token_id
+    }
+
+    //get the time in milliseconds after which the token can breed again
+    pub fn nft_breeding_cooldown(&self, token_id: TokenId) -> Option<u64> {
+        self.breeding_cooldowns.get(&token_id)
+    }
+
+    //get the family tree of a token, starting with the token itself and going up max_depth generations
+    pub fn nft_lineage(&self, token_id: TokenId, max_depth: Option<u32>) -> Vec<JsonLineage> {
+        let max_depth = max_depth.unwrap_or(MAX_LINEAGE_DEPTH).min(MAX_LINEAGE_DEPTH);
+
+        let mut family_tree = vec![];
+        //tokens to look up along with how far up the tree they are
+        let mut to_visit = vec![(token_id, 0)];
+        while !to_visit.is_empty() {
+            let (token_id, depth) = to_visit.remove(0);
+            let lineage = self.lineage_by_id.get(&token_id);
+
+            //if the token was bred and we aren't too deep yet, we visit its parents next
+            if let Some(lineage) = lineage.as_ref() {
+                if depth < max_depth {
+                    to_visit.push((lineage.parents.0.clone(), depth + 1));
+                    to_visit.push((lineage.parents.1.clone(), depth + 1));
+                }
+            }
+
+            family_tree.push(JsonLineage {
+                token_id,
+                generation: lineage.as_ref().map(|lineage| lineage.generation).unwrap_or(0),
+                parents: lineage.map(|lineage| lineage.parents),
+            });
+        }
+
+        family_tree
+    }
+}
+
+impl Contract {
+    //make sure the token is a pet owned by the owner that is off cooldown and return its attributes
+    fn internal_assert_can_breed(&self, owner_id: &AccountId, token_id: &TokenId, now: u64) -> TokenAttributes {
+        let token = self.tokens_by_id.get(token_id).expect("No token");
+        assert_eq!(&token.owner_id, owner_id, "Only the owner of both pets can breed them");
+        self.assert_valid(token_id);
+
+        let attributes = self.attributes_by_id.get(token_id).expect("Token has no attributes");
+        assert!(attributes.category == TokenCategory::Pet, "Only pets can breed");
+
+        if let Some(cooldown) = self.breeding_cooldowns.get(token_id) {
+            assert!(now >= cooldown, "Pet {} is on breeding cooldown until {}", token_id, cooldown);
+        }
+
+        attributes
+    }
+
+    //get the generation of a token (0 if it wasn't bred)
+    fn internal_generation(&self, token_id: &TokenId) -> u32 {
+        self.lineage_by_id.get(token_id).map(|lineage| lineage.generation).unwrap_or(0)
+    }
+}
+
+//derive the attributes of a child from its parents plus randomness
+fn child_attributes(parent_a: &TokenAttributes, parent_b: &TokenAttributes, salt: &[u8]) -> TokenAttributes {
+    //the child inherits the rarity of one of its parents, with a small chance of mutating
+    let rarity = if random_u64(&[salt, b"mutation"].concat()) % 10_000 < RARITY_MUTATION_CHANCE {
+        random_rarity(salt)
+    } else if random_u64(&[salt, b"rarity"].concat()) % 10_000 < 5_000 {
+        parent_a.rarity
+    } else {
+        parent_b.rarity
+    };
+
+    //the child gets the stats both parents have first, then the ones only one of them has, up to the maximum number of stats
+    let (mut shared, mut single): (Vec<&String>, Vec<&String>) = parent_a
+        .stats
+        .keys()
+        .chain(parent_b.stats.keys().filter(|name| !parent_a.stats.contains_key(*name)))
+        .partition(|name| parent_a.stats.contains_key(*name) && parent_b.stats.contains_key(*name));
+    shared.sort();
+    single.sort();
+
+    //every stat is averaged and varied by a random factor between 80% and 120%
+    let mut stats = HashMap::new();
+    for name in shared.into_iter().chain(single).take(MAX_STATS) {
+        let average = (*parent_a.stats.get(name).unwrap_or(&0) as u64 + *parent_b.stats.get(name).unwrap_or(&0) as u64) / 2;
+        let factor = 80 + random_u64(&[salt, name.as_bytes()].concat()) % 41;
+        stats.insert(name.clone(), (average * factor / 100) as u32);
+    }
+
+    TokenAttributes {
+        category: TokenCategory::Pet,
+        rarity,
+        level: 1,
+        stats,
+    }
+}
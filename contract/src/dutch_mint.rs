@@ -0,0 +1,142 @@
+use crate::*;
+
+//schedule of the Dutch auction sale of the genesis collection
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DutchAuctionConfig {
+    //when the auction starts, Unix epoch in milliseconds
+    pub start_time: u64,
+    //price in yoctoNEAR when the auction starts
+    pub start_price: U128,
+    //the price never goes below this
+    pub floor_price: U128,
+    //how much the price drops every drop interval
+    pub price_drop: U128,
+    //time in milliseconds between price drops
+    pub drop_interval: u64,
+    //if true, minters can claim back the difference to the final price once the collection sells out
+    pub rebate: bool,
+}
+
+//what an account payed during the Dutch auction
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DutchAuctionPurchase {
+    pub count: u64,
+    pub payed: Balance,
+    //sum of the discounts in basis points the purchases were made with
+    pub discount: u64,
+}
+
+#[near_bindgen]
+impl Contract {
+    //set the schedule of the Dutch auction. Passing in None goes back to the fixed price sale.
+    pub fn set_dutch_auction(&mut self, config: Option<DutchAuctionConfig>) {
+        self.assert_owner();
+        if let Some(current) = self.dutch_auction.as_ref() {
+            assert!(
+                env::block_timestamp() / 1000000 < current.start_time,
+                "The Dutch auction already started"
+            );
+        }
+        if let Some(config) = config.as_ref() {
+            assert!(
+                u128::from(config.start_price) >= u128::from(config.floor_price),
+                "Start price can't be less than the floor price"
+            );
+            assert!(config.drop_interval > 0, "Drop interval must be greater than 0");
+        }
+        self.dutch_auction = config;
+    }
+
+    //get the schedule of the Dutch auction
+    pub fn get_dutch_auction(&self) -> Option<DutchAuctionConfig> {
+        self.dutch_auction.clone()
+    }
+
+    //get the price a genesis token currently costs during the Dutch auction
+    pub fn get_dutch_auction_price(&self) -> U128 {
+        U128(self.dutch_auction_price_at(env::block_timestamp() / 1000000))
+    }
+
+    //get the final price of the Dutch auction. Returns None until the collection sold out.
+    pub fn get_dutch_auction_clearing_price(&self) -> Option<U128> {
+        if self.genesis_tokens_minted < GENESIS_SUPPLY {
+            return None;
+        }
+        self.dutch_auction_last_price.map(U128)
+    }
+
+    //get the rebate an account can claim once the Dutch auction sold out
+    pub fn get_dutch_auction_rebate(&self, account_id: AccountId) -> U128 {
+        U128(self.internal_dutch_auction_rebate(&account_id))
+    }
+
+    //claim back the difference between what the caller payed and the final price of the Dutch auction
+    #[payable]
+    pub fn claim_dutch_auction_rebate(&mut self) -> U128 {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+
+        let rebate_enabled = self.dutch_auction.as_ref().map(|config| config.rebate).unwrap_or(false);
+        assert!(rebate_enabled, "The Dutch auction has no rebates");
+        assert!(self.genesis_tokens_minted >= GENESIS_SUPPLY, "Rebates can be claimed once the collection sold out");
+
+        let rebate = self.internal_dutch_auction_rebate(&account_id);
+        self.dutch_auction_purchases.remove(&account_id);
+        if rebate > 0 {
+            Promise::new(account_id).transfer(rebate);
+        }
+        U128(rebate)
+    }
+}
+
+impl Contract {
+    //get the price of a genesis token at the passed in time in milliseconds during the Dutch auction
+    pub(crate) fn dutch_auction_price_at(&self, timestamp: u64) -> Balance {
+        let config = self.dutch_auction.as_ref().expect("No Dutch auction");
+        let drops = Balance::from(timestamp.saturating_sub(config.start_time) / config.drop_interval);
+        config
+            .start_price
+            .0
+            .saturating_sub(drops.saturating_mul(config.price_drop.0))
+            .max(config.floor_price.0)
+    }
+
+    /*
+        keep track of a genesis token bought during the Dutch auction. The schedule price becomes the last price,
+        the account payed the price after the discount in basis points.
+    */
+    pub(crate) fn internal_record_dutch_auction_purchase(&mut self, account_id: &AccountId, schedule_price: Balance, price: Balance, discount: u32) {
+        self.dutch_auction_last_price = Some(schedule_price);
+
+        //purchases only need to be stored if the difference to the final price is refunded
+        if self.dutch_auction.as_ref().map(|config| config.rebate).unwrap_or(false) {
+            let mut purchase = self.dutch_auction_purchases.get(account_id).unwrap_or(DutchAuctionPurchase {
+                count: 0,
+                payed: 0,
+                discount: 0,
+            });
+            purchase.count += 1;
+            purchase.payed += price;
+            purchase.discount += u64::from(discount);
+            self.dutch_auction_purchases.insert(account_id, &purchase);
+        }
+    }
+
+    //get the difference between what the account payed and the final price of the Dutch auction after their discounts
+    fn internal_dutch_auction_rebate(&self, account_id: &AccountId) -> Balance {
+        let clearing_price = match self.get_dutch_auction_clearing_price() {
+            Some(clearing_price) => clearing_price.0,
+            None => return 0,
+        };
+        self.dutch_auction_purchases
+            .get(account_id)
+            .map(|purchase| {
+                let owed = (clearing_price * Balance::from(purchase.count))
+                    .saturating_sub(clearing_price * Balance::from(purchase.discount) / 10_000);
+                purchase.payed.saturating_sub(owed)
+            })
+            .unwrap_or(0)
+    }
+}
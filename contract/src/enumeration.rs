@@ -3,6 +3,12 @@ use crate::*;
 #[near_bindgen]
 impl Contract {
 
+    //Query for the total supply of NFTs on the contract
+    pub fn nft_total_supply(&self) -> U128 {
+        //return the length of the token metadata by ID
+        U128(self.token_metadata_by_id.len() as u128)
+    }
+
     //Query for nft tokens on the contract regardless of the owner using pagination
     pub fn nft_tokens(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonToken> {
         //get a vector of the keys in the token_metadata_by_id collection.  
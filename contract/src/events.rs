@@ -2,8 +2,12 @@ use std::fmt;
 
 use near_sdk::serde::{Deserialize, Serialize};
 
+use crate::auctions::AuctionKind;
+
 /// Enum that represents the data type of the EventLog.
-/// The enum can either be an NftMint or an NftTransfer.
+/// The enum can either be an NftMint, an NftTransfer, an NftBurn or an NftMetadataUpdate for non-fungible tokens,
+/// or an MtMint, an MtTransfer or an MtBurn for multi tokens.
+/// The remaining variants are events of the game that aren't part of a NEP standard.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(tag = "event", content = "data")]
 #[serde(rename_all = "snake_case")]
@@ -12,6 +16,21 @@ use near_sdk::serde::{Deserialize, Serialize};
 pub enum EventLogVariant {
     NftMint(Vec<NftMintLog>),
     NftTransfer(Vec<NftTransferLog>),
+    NftBurn(Vec<NftBurnLog>),
+    NftMetadataUpdate(Vec<NftMetadataUpdateLog>),
+    MtMint(Vec<MtMintLog>),
+    MtTransfer(Vec<MtTransferLog>),
+    MtBurn(Vec<MtBurnLog>),
+    NftRentalStart(Vec<NftRentalLog>),
+    NftRentalEnd(Vec<NftRentalLog>),
+    NftList(Vec<NftListLog>),
+    NftDelist(Vec<NftDelistLog>),
+    NftSale(Vec<NftSaleLog>),
+    NftOffer(Vec<NftOfferLog>),
+    NftOfferCancel(Vec<NftOfferCancelLog>),
+    NftAuctionStart(Vec<NftAuctionStartLog>),
+    NftBid(Vec<NftBidLog>),
+    RaffleDraw(Vec<RaffleDrawLog>),
 }
 
 /// Interface to capture data about an event
@@ -78,6 +97,251 @@ pub struct NftTransferLog {
     pub memo: Option<String>,
 }
 
+/// An event log to capture token burning
+///
+/// Arguments
+/// * `owner_id`: owner of the burned tokens
+/// * `authorized_id`: approved account to burn
+/// * `token_ids`: ["1", "abc"]
+/// * `memo`: optional message
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftBurnLog {
+    pub owner_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<String>,
+
+    pub token_ids: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// An event log to capture token metadata updates
+///
+/// Arguments
+/// * `token_ids`: ["1", "abc"]
+/// * `memo`: optional message
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftMetadataUpdateLog {
+    pub token_ids: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// An event log to capture multi token minting
+///
+/// Arguments
+/// * `owner_id`: "account.near"
+/// * `token_ids`: ["shard", "potion"]
+/// * `amounts`: ["10", "1"]
+/// * `memo`: optional message
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MtMintLog {
+    pub owner_id: String,
+    pub token_ids: Vec<String>,
+    pub amounts: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// An event log to capture multi token transfer
+///
+/// Arguments
+/// * `authorized_id`: approved account to transfer
+/// * `old_owner_id`: "owner.near"
+/// * `new_owner_id`: "receiver.near"
+/// * `token_ids`: ["shard", "potion"]
+/// * `amounts`: ["10", "1"]
+/// * `memo`: optional message
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MtTransferLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<String>,
+
+    pub old_owner_id: String,
+    pub new_owner_id: String,
+    pub token_ids: Vec<String>,
+    pub amounts: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// An event log to capture multi token burning
+///
+/// Arguments
+/// * `owner_id`: owner of the burned units
+/// * `authorized_id`: approved account to burn
+/// * `token_ids`: ["shard", "potion"]
+/// * `amounts`: ["10", "1"]
+/// * `memo`: optional message
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MtBurnLog {
+    pub owner_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<String>,
+
+    pub token_ids: Vec<String>,
+    pub amounts: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+/// An event log to capture the start or end of a token rental
+///
+/// Arguments
+/// * `token_id`: "1"
+/// * `owner_id`: "owner.near"
+/// * `user_id`: "player.near"
+/// * `expires`: time in milliseconds when the rental ends
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftRentalLog {
+    pub token_id: String,
+    pub owner_id: String,
+    pub user_id: String,
+    pub expires: u64,
+}
+
+/// An event log to capture a token being listed on the market
+///
+/// Arguments
+/// * `token_id`: "1"
+/// * `owner_id`: "owner.near"
+/// * `price`: price in yoctoNEAR as a string
+/// * `expires`: optional time in milliseconds when the listing expires
+/// * `ft_contract_id`: optional fungible token the price is in
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftListLog {
+    pub token_id: String,
+    pub owner_id: String,
+    pub price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ft_contract_id: Option<String>,
+}
+
+/// An event log to capture a token being taken off the market by its owner
+///
+/// Arguments
+/// * `token_id`: "1"
+/// * `owner_id`: "owner.near"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftDelistLog {
+    pub token_id: String,
+    pub owner_id: String,
+}
+
+/// An event log to capture a token being sold on the market
+///
+/// Arguments
+/// * `token_id`: "1"
+/// * `seller_id`: "seller.near"
+/// * `buyer_id`: "buyer.near"
+/// * `price`: price in yoctoNEAR as a string
+/// * `market_fee`: part of the price in yoctoNEAR taken by the market as a string
+/// * `ft_contract_id`: optional fungible token the price was payed in
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftSaleLog {
+    pub token_id: String,
+    pub seller_id: String,
+    pub buyer_id: String,
+    pub price: String,
+    pub market_fee: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ft_contract_id: Option<String>,
+}
+
+/// An event log to capture an offer being made
+///
+/// Arguments
+/// * `offer_id`: 0
+/// * `buyer_id`: "buyer.near"
+/// * `token_id`: "1" or None for collection offers
+/// * `price`: price per token in yoctoNEAR as a string
+/// * `count`: number of tokens the buyer wants
+/// * `expires`: optional time in milliseconds when the offer expires
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftOfferLog {
+    pub offer_id: u64,
+    pub buyer_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+    pub price: String,
+    pub count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<u64>,
+}
+
+/// An event log to capture an offer being cancelled by its buyer
+///
+/// Arguments
+/// * `offer_id`: 0
+/// * `buyer_id`: "buyer.near"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftOfferCancelLog {
+    pub offer_id: u64,
+    pub buyer_id: String,
+}
+
+/// An event log to capture the start of an auction
+///
+/// Arguments
+/// * `token_id`: "1"
+/// * `seller_id`: "seller.near"
+/// * `kind`: the English or Dutch auction parameters
+/// * `ends_at`: time in milliseconds when the auction ends
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftAuctionStartLog {
+    pub token_id: String,
+    pub seller_id: String,
+    pub kind: AuctionKind,
+    pub ends_at: u64,
+}
+
+/// An event log to capture a bid on an auction
+///
+/// Arguments
+/// * `token_id`: "1"
+/// * `bidder_id`: "bidder.near"
+/// * `amount`: bid in yoctoNEAR as a string
+/// * `ends_at`: time in milliseconds when the auction ends after the bid
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftBidLog {
+    pub token_id: String,
+    pub bidder_id: String,
+    pub amount: String,
+    pub ends_at: u64,
+}
+
+/// An event log to capture the winners of the raffle drawn in a batch
+///
+/// Arguments
+/// * `winners`: ["winner1.near", "winner2.near"]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RaffleDrawLog {
+    pub winners: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +399,52 @@ mod tests {
         };
         assert_eq!(expected, log.to_string());
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn nep_format_metadata_update() {
+        let expected = r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_metadata_update","data":[{"token_ids":["1","2"]}]}"#;
+        let log = EventLog {
+            standard: "nep171".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::NftMetadataUpdate(vec![NftMetadataUpdateLog {
+                token_ids: vec!["1".to_string(), "2".to_string()],
+                memo: None,
+            }]),
+        };
+        assert_eq!(expected, log.to_string());
+    }
+
+    #[test]
+    fn nep_format_mt_transfer() {
+        let expected = r#"EVENT_JSON:{"standard":"nep245","version":"1.0.0","event":"mt_transfer","data":[{"old_owner_id":"user1.near","new_owner_id":"user2.near","token_ids":["shard"],"amounts":["10"]}]}"#;
+        let log = EventLog {
+            standard: "nep245".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::MtTransfer(vec![MtTransferLog {
+                authorized_id: None,
+                old_owner_id: "user1.near".to_string(),
+                new_owner_id: "user2.near".to_string(),
+                token_ids: vec!["shard".to_string()],
+                amounts: vec!["10".to_string()],
+                memo: None,
+            }]),
+        };
+        assert_eq!(expected, log.to_string());
+    }
+
+    #[test]
+    fn game_format_rental_start() {
+        let expected = r#"EVENT_JSON:{"standard":"glorygames","version":"1.0.0","event":"nft_rental_start","data":[{"token_id":"1","owner_id":"user1.near","user_id":"user2.near","expires":1663857000000}]}"#;
+        let log = EventLog {
+            standard: "glorygames".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::NftRentalStart(vec![NftRentalLog {
+                token_id: "1".to_string(),
+                owner_id: "user1.near".to_string(),
+                user_id: "user2.near".to_string(),
+                expires: 1663857000000,
+            }]),
+        };
+        assert_eq!(expected, log.to_string());
+    }
+}
\ No newline at end of file
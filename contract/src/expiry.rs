@@ -0,0 +1,71 @@
+use crate::*;
+
+//maximum number of tokens purge_expired looks at in a single call (GAS limits this)
+const MAX_PURGE_SCAN: u64 = 100;
+
+#[near_bindgen]
+impl Contract {
+    //set for how long in milliseconds tokens of a type are valid after they are minted. Passing in None removes the limit.
+    pub fn set_token_type_validity(&mut self, token_type_id: TokenTypeId, valid_for: Option<u64>) {
+        self.assert_owner();
+        let mut token_type = self.token_types_by_id.get(&token_type_id).expect("No token type");
+        token_type.valid_for = valid_for;
+        self.token_types_by_id.insert(&token_type_id, &token_type);
+    }
+
+    //check if a token has started being valid and hasn't expired yet
+    pub fn nft_is_valid(&self, token_id: TokenId) -> bool {
+        let metadata = self.token_metadata_by_id.get(&token_id).expect("No token");
+        let now = env::block_timestamp() / 1000000;
+
+        metadata.starts_at.map(|starts_at| now >= starts_at).unwrap_or(true)
+            && metadata.expires_at.map(|expires_at| now < expires_at).unwrap_or(true)
+    }
+
+    /*
+        burn the expired tokens among `limit` tokens that can expire, starting at from_index.
+        anyone can call this. Staked and auctioned tokens are skipped until they are unstaked or settled.
+        returns the number of tokens that were burned.
+    */
+    pub fn purge_expired(&mut self, from_index: Option<U128>, limit: Option<u64>) -> u64 {
+        //where to start scanning - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        let limit = limit.unwrap_or(MAX_PURGE_SCAN).min(MAX_PURGE_SCAN);
+
+        //find the expired tokens and group them by owner so we can log one burn per owner
+        let mut expired_per_owner: HashMap<AccountId, Vec<TokenId>> = HashMap::new();
+        for token_id in self.expiring_tokens.iter().skip(start as usize).take(limit as usize) {
+            if self.is_expired(&token_id) && !self.is_staked(&token_id) && !self.is_auctioned(&token_id) {
+                let owner_id = self.tokens_by_id.get(&token_id).unwrap().owner_id;
+                expired_per_owner.entry(owner_id).or_default().push(token_id);
+            }
+        }
+
+        let mut purged = 0;
+        for (owner_id, token_ids) in expired_per_owner {
+            for token_id in token_ids.iter() {
+                self.internal_burn(&owner_id, token_id);
+            }
+            purged += token_ids.len() as u64;
+            self.internal_log_burn(&owner_id, token_ids, Some("Expired".to_string()));
+        }
+
+        purged
+    }
+}
+
+impl Contract {
+    //check if a token's expires_at is in the past
+    pub(crate) fn is_expired(&self, token_id: &TokenId) -> bool {
+        self.token_metadata_by_id
+            .get(token_id)
+            .and_then(|metadata| metadata.expires_at)
+            .map(|expires_at| env::block_timestamp() / 1000000 >= expires_at)
+            .unwrap_or(false)
+    }
+
+    //make sure that a token can be used in game functions
+    pub(crate) fn assert_valid(&self, token_id: &TokenId) {
+        assert!(self.nft_is_valid(token_id.clone()), "Token {} is not valid at this time", token_id);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::*;
+use near_sdk::{ext_contract, Gas, PromiseResult};
+
+const GAS_FOR_FT_TRANSFER: Gas = Gas(10_000_000_000_000);
+const GAS_FOR_RESOLVE_WITHDRAW_FT: Gas = Gas(5_000_000_000_000);
+const NO_DEPOSIT: Balance = 0;
+//maximum number of tokens that can be minted with a single transfer (GAS limits this)
+const MAX_FT_MINT_COUNT: u32 = 3;
+
+#[ext_contract(ext_fungible_token)]
+pub trait FungibleTokenCore {
+    //transfers fungible tokens to the receiver ID
+    fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
+}
+
+#[ext_contract(ext_ft_withdraw_resolver)]
+trait FtWithdrawResolver {
+    //resolves the withdrawal of mint proceeds and gives them back to the owner to withdraw if the transfer failed
+    fn ft_resolve_withdraw(&mut self, ft_contract_id: AccountId, amount: U128);
+}
+
+//the mint parameters passed in the msg of ft_transfer_call
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtMintArgs {
+    //number of genesis tokens to mint
+    pub count: u32,
+}
+
+//what the fungible tokens sent with ft_transfer_call are used for
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde", untagged)]
+pub enum FtTransferMsg {
+    //buy a token listed in the fungible token
+    Buy { buy_token_id: TokenId },
+    //mint genesis tokens
+    Mint(FtMintArgs),
+}
+
+#[near_bindgen]
+impl Contract {
+    //set the price of a genesis token in a fungible token for a sale phase. Passing in None stops accepting the token in that phase.
+    pub fn set_ft_mint_price(&mut self, ft_contract_id: AccountId, phase: SalePhase, price: Option<U128>) {
+        self.assert_owner();
+        //the Dutch auction price only exists in NEAR
+        assert!(phase != SalePhase::DutchAuction, "Fungible tokens are not accepted during the Dutch auction");
+        let mut prices = self.ft_mint_prices.get(&ft_contract_id).unwrap_or_default();
+        if let Some(price) = price {
+            prices.insert(phase, price.into());
+        } else {
+            prices.remove(&phase);
+        }
+
+        //if the token isn't accepted in any phase anymore, we remove it
+        if prices.is_empty() {
+            self.ft_mint_prices.remove(&ft_contract_id);
+        } else {
+            self.ft_mint_prices.insert(&ft_contract_id, &prices);
+        }
+    }
+
+    //get the prices of a genesis token in a fungible token for every sale phase it is accepted in
+    pub fn get_ft_mint_prices(&self, ft_contract_id: AccountId) -> HashMap<SalePhase, U128> {
+        self.ft_mint_prices
+            .get(&ft_contract_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(phase, price)| (phase, U128(price)))
+            .collect()
+    }
+
+    //get the fungible tokens that are accepted for minting
+    pub fn get_mint_fts(&self) -> Vec<AccountId> {
+        self.ft_mint_prices.keys().collect()
+    }
+
+    /*
+        pay with a fungible token (NEP-141). The msg is either the mint parameters as JSON ({"count": 2}),
+        defaulting to minting a single token, or the token to buy from the market ({"buy_token_id": "12"}).
+        Mints draw their storage from the prepaid storage balance of the sender. The unused amount is returned to the sender.
+    */
+    pub fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128> {
+        let ft_contract_id = env::predecessor_account_id();
+        let msg = if msg.is_empty() {
+            FtTransferMsg::Mint(FtMintArgs { count: 1 })
+        } else {
+            near_sdk::serde_json::from_str::<FtTransferMsg>(&msg).expect("Invalid msg")
+        };
+
+        let unused = match msg {
+            FtTransferMsg::Buy { buy_token_id } => self.internal_ft_buy(&ft_contract_id, &sender_id, buy_token_id, amount.0),
+            FtTransferMsg::Mint(args) => self.internal_ft_mint(&ft_contract_id, &sender_id, args, amount.0),
+        };
+
+        //return the unused amount to the sender
+        PromiseOrValue::Value(U128(unused))
+    }
+
+    //get the mint proceeds in a fungible token that the owner can withdraw
+    pub fn get_ft_mint_proceeds(&self, ft_contract_id: AccountId) -> U128 {
+        U128(self.ft_mint_proceeds.get(&ft_contract_id).unwrap_or(0))
+    }
+
+    //withdraw fungible tokens payed for mints to the owner. Tokens held for anything else, like unclaimed payouts, stay.
+    pub fn withdraw_ft(&mut self, ft_contract_id: AccountId, amount: U128) -> Promise {
+        self.assert_owner();
+        let proceeds = self.ft_mint_proceeds.get(&ft_contract_id).unwrap_or(0);
+        assert!(amount.0 <= proceeds, "Can't withdraw more than the {} mint proceeds", proceeds);
+        self.ft_mint_proceeds.insert(&ft_contract_id, &(proceeds - amount.0));
+
+        ext_fungible_token::ft_transfer(
+            self.owner_id.clone(),
+            amount,
+            Some("Mint proceeds".to_string()),
+            ft_contract_id.clone(), //contract account to make the call to
+            1, //attached deposit
+            GAS_FOR_FT_TRANSFER, //GAS attached to the call
+        )
+        //we then resolve the withdrawal on our own contract
+        .then(ext_ft_withdraw_resolver::ft_resolve_withdraw(
+            ft_contract_id,
+            amount,
+            env::current_account_id(), //contract account to make the call to
+            NO_DEPOSIT, //attached deposit
+            GAS_FOR_RESOLVE_WITHDRAW_FT, //GAS attached to the call
+        ))
+    }
+
+    #[private]
+    pub fn ft_resolve_withdraw(&mut self, ft_contract_id: AccountId, amount: U128) {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return;
+        }
+        //the transfer failed so the owner can withdraw the proceeds again
+        let proceeds = self.ft_mint_proceeds.get(&ft_contract_id).unwrap_or(0);
+        self.ft_mint_proceeds.insert(&ft_contract_id, &(proceeds + amount.0));
+    }
+}
+
+impl Contract {
+    //mint genesis tokens for the sender paid for with a fungible token and return the unused amount
+    fn internal_ft_mint(&mut self, ft_contract_id: &AccountId, sender_id: &AccountId, args: FtMintArgs, amount: Balance) -> Balance {
+        let prices = self.ft_mint_prices.get(ft_contract_id).expect("Token is not accepted for minting");
+        assert!(
+            args.count > 0 && args.count <= MAX_FT_MINT_COUNT,
+            "Can mint between 1 and {} tokens at once",
+            MAX_FT_MINT_COUNT
+        );
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let now = env::block_timestamp() / 1000000;
+        let mut spent: Balance = 0;
+        let mut token_ids = vec![];
+        for _ in 0..args.count {
+            let my_token_id = self.genesis_tokens_minted + 1;
+            assert!(my_token_id <= GENESIS_SUPPLY, "Minting ended");
+
+            //the same eligibility rules as for nft_mint apply, but there are no free mints
+            let (phase, _) = self.internal_assert_can_mint(sender_id, false, now);
+            //Dutch auction purchases set the clearing price and get rebates, which only works for NEAR
+            assert!(
+                phase != Some(SalePhase::DutchAuction),
+                "Fungible tokens are not accepted during the Dutch auction"
+            );
+            let price = phase
+                .and_then(|phase| prices.get(&phase))
+                .expect("Token is not accepted in this phase");
+            if phase == Some(SalePhase::Raffle) {
+                self.internal_take_raffle_allocation(sender_id);
+            }
+
+            spent += price;
+            assert!(spent <= amount, "Must transfer at least {} to mint", spent);
+            token_ids.push(self.internal_mint_genesis(sender_id, my_token_id, phase, now));
+        }
+
+        //Paras takes the same part of the price as for NEAR mints. It's kept for them to claim with claim_ft_payout.
+        let paras_fee = royalty_to_payout(PARAS_FEE, spent).0;
+        if paras_fee > 0 {
+            self.internal_add_unclaimed_ft_payout(
+                ft_contract_id.clone(),
+                PARAS_ACCOUNT_ID.to_string().try_into().unwrap(),
+                paras_fee,
+            );
+        }
+
+        //keep track of what the owner can withdraw
+        let proceeds = self.ft_mint_proceeds.get(ft_contract_id).unwrap_or(0);
+        self.ft_mint_proceeds.insert(ft_contract_id, &(proceeds + spent - paras_fee));
+
+        //there is no NEAR attached, so the storage is drawn from the storage balance of the sender
+        let storage_cost = env::storage_byte_cost() * Balance::from(env::storage_usage() - initial_storage_usage);
+        self.internal_draw_storage_balance(sender_id, storage_cost).unwrap_or_else(|| {
+            env::panic_str(&format!(
+                "Must prepay {} yoctoNEAR of storage with storage_deposit to mint with a fungible token",
+                storage_cost
+            ))
+        });
+
+        self.internal_log_mint(sender_id, token_ids);
+
+        amount - spent
+    }
+}
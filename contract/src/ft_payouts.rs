@@ -0,0 +1,151 @@
+use crate::*;
+use near_sdk::{ext_contract, Gas, PromiseResult};
+
+const GAS_FOR_STORAGE_BALANCE_OF: Gas = Gas(5_000_000_000_000);
+const GAS_FOR_FT_TRANSFER: Gas = Gas(10_000_000_000_000);
+const GAS_FOR_RESOLVE_PAYOUT_TRANSFER: Gas = Gas(5_000_000_000_000);
+const GAS_FOR_RESOLVE_STORAGE_CHECK: Gas = Gas(
+    GAS_FOR_FT_TRANSFER.0 + GAS_FOR_RESOLVE_PAYOUT_TRANSFER.0 + 10_000_000_000_000,
+);
+//GAS used up by paying out a single receiver (the storage check and its resolve chain)
+const GAS_PER_FT_PAYOUT: Gas = Gas(GAS_FOR_STORAGE_BALANCE_OF.0 + GAS_FOR_RESOLVE_STORAGE_CHECK.0);
+const NO_DEPOSIT: Balance = 0;
+
+#[ext_contract(ext_storage_management)]
+trait StorageManagement {
+    //get the storage balance of an account. Returns None if the account isn't registered.
+    fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance>;
+}
+
+#[ext_contract(ext_ft_payout_resolver)]
+trait FtPayoutResolver {
+    /*
+        resolves the storage check of a payout receiver on the fungible token contract.
+        registered receivers are sent their payout, the payout of everyone else is kept for them to claim.
+    */
+    fn ft_resolve_storage_check(&mut self, ft_contract_id: AccountId, receiver_id: AccountId, amount: U128);
+
+    //resolves the transfer of a payout and keeps the payout for the receiver to claim if it failed
+    fn ft_resolve_payout_transfer(&mut self, ft_contract_id: AccountId, receiver_id: AccountId, amount: U128);
+}
+
+#[near_bindgen]
+impl Contract {
+    //get the fungible tokens from sales that are waiting for the account to claim them
+    pub fn get_unclaimed_ft_payout(&self, ft_contract_id: AccountId, account_id: AccountId) -> U128 {
+        U128(self.unclaimed_ft_payouts.get(&(ft_contract_id, account_id)).unwrap_or(0))
+    }
+
+    //claim the fungible tokens from sales that couldn't be sent to the caller. The caller has to be registered with the token contract.
+    pub fn claim_ft_payout(&mut self, ft_contract_id: AccountId) -> Promise {
+        let receiver_id = env::predecessor_account_id();
+        let amount = self
+            .unclaimed_ft_payouts
+            .remove(&(ft_contract_id.clone(), receiver_id.clone()))
+            .expect("Nothing to claim");
+        ft_payout_transfer(ft_contract_id, receiver_id, amount)
+    }
+
+    #[private]
+    pub fn ft_resolve_storage_check(&mut self, ft_contract_id: AccountId, receiver_id: AccountId, amount: U128) {
+        //the receiver is registered if the token contract returned a storage balance
+        let registered = match env::promise_result(0) {
+            PromiseResult::Successful(value) => near_sdk::serde_json::from_slice::<Option<StorageBalance>>(&value)
+                .map(|storage_balance| storage_balance.is_some())
+                .unwrap_or(false),
+            _ => false,
+        };
+
+        if registered {
+            ft_payout_transfer(ft_contract_id, receiver_id, amount.0);
+        } else {
+            self.internal_add_unclaimed_ft_payout(ft_contract_id, receiver_id, amount.0);
+        }
+    }
+
+    #[private]
+    pub fn ft_resolve_payout_transfer(&mut self, ft_contract_id: AccountId, receiver_id: AccountId, amount: U128) {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return;
+        }
+        //the transfer failed so the receiver can claim the payout later
+        self.internal_add_unclaimed_ft_payout(ft_contract_id, receiver_id, amount.0);
+    }
+}
+
+impl Contract {
+    /*
+        pay out the price of a sold token in a fungible token. The contract owner takes the market fee and the rest is
+        payed out to the previous owner and royalty receivers. Returns the market fee.
+    */
+    pub(crate) fn internal_pay_out_sale_ft(&self, previous_token: &Token, ft_contract_id: &AccountId, price: Balance) -> Balance {
+        let (payout, market_fee) = self.internal_sale_payout(previous_token, price);
+        for (receiver_id, amount) in payout.payout {
+            if amount.0 > 0 {
+                ft_distribute(ft_contract_id.clone(), receiver_id, amount);
+            }
+        }
+
+        market_fee
+    }
+
+    //make sure there is enough GAS left to pay out the sale of the token for the price to every receiver
+    pub(crate) fn assert_ft_payout_gas(&self, token: &Token, price: Balance) {
+        let (payout, _) = self.internal_sale_payout(token, price);
+        let receivers = payout.payout.values().filter(|amount| amount.0 > 0).count();
+        let required_gas = Gas(GAS_PER_FT_PAYOUT.0 * receivers as u64);
+        assert!(
+            env::prepaid_gas() - env::used_gas() >= required_gas,
+            "You cannot attach less than {:?} Gas to pay out {} receivers",
+            required_gas,
+            receivers
+        );
+    }
+
+    //keep fungible tokens for the receiver to claim
+    pub(crate) fn internal_add_unclaimed_ft_payout(&mut self, ft_contract_id: AccountId, receiver_id: AccountId, amount: Balance) {
+        let key = (ft_contract_id, receiver_id);
+        let unclaimed = self.unclaimed_ft_payouts.get(&key).unwrap_or(0) + amount;
+        self.unclaimed_ft_payouts.insert(&key, &unclaimed);
+    }
+}
+
+//check that the receiver is registered with the token contract before sending it the payout
+fn ft_distribute(ft_contract_id: AccountId, receiver_id: AccountId, amount: U128) -> Promise {
+    ext_storage_management::storage_balance_of(
+        receiver_id.clone(),
+        ft_contract_id.clone(), //contract account to make the call to
+        NO_DEPOSIT, //attached deposit
+        GAS_FOR_STORAGE_BALANCE_OF, //GAS attached to the call
+    )
+    //we then resolve the storage check on our own contract
+    .then(ext_ft_payout_resolver::ft_resolve_storage_check(
+        ft_contract_id,
+        receiver_id,
+        amount,
+        env::current_account_id(), //contract account to make the call to
+        NO_DEPOSIT, //attached deposit
+        GAS_FOR_RESOLVE_STORAGE_CHECK, //GAS attached to the call
+    ))
+}
+
+//send a payout to the receiver and keep it for them to claim if the transfer fails
+fn ft_payout_transfer(ft_contract_id: AccountId, receiver_id: AccountId, amount: Balance) -> Promise {
+    ext_fungible_token::ft_transfer(
+        receiver_id.clone(),
+        U128(amount),
+        Some("Sale payout".to_string()),
+        ft_contract_id.clone(), //contract account to make the call to
+        1, //attached deposit
+        GAS_FOR_FT_TRANSFER, //GAS attached to the call
+    )
+    //we then resolve the transfer on our own contract
+    .then(ext_ft_payout_resolver::ft_resolve_payout_transfer(
+        ft_contract_id,
+        receiver_id,
+        U128(amount),
+        env::current_account_id(), //contract account to make the call to
+        NO_DEPOSIT, //attached deposit
+        GAS_FOR_RESOLVE_PAYOUT_TRANSFER, //GAS attached to the call
+    ))
+}
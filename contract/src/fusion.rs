@@ -0,0 +1,150 @@
+use crate::*;
+
+//maximum number of tokens that can be burned in a single fusion (GAS limits this)
+const MAX_FUSION_INPUTS: u32 = 20;
+//maximum number of different multi tokens a fusion can burn units of
+const MAX_FUSION_MT_INPUTS: usize = 5;
+
+pub type RecipeId = String;
+
+//combines tokens of the input types into a token of the output type
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Recipe {
+    //how many tokens of each type need to be burned
+    pub inputs: HashMap<TokenTypeId, u32>,
+    //how many units of each multi token (like egg shards) need to be burned
+    #[serde(default)]
+    pub mt_inputs: HashMap<TokenId, U128>,
+    //type of the token that is minted
+    pub output: TokenTypeId,
+}
+
+#[near_bindgen]
+impl Contract {
+    //create a new fusion recipe. The owner needs to attach enough to cover the storage.
+    #[payable]
+    pub fn create_recipe(&mut self, recipe_id: RecipeId, recipe: Recipe) {
+        self.assert_owner();
+
+        //make sure that all the types exist and the number of inputs is reasonable
+        let total_inputs: u32 = recipe.inputs.values().sum();
+        assert!(
+            total_inputs <= MAX_FUSION_INPUTS,
+            "A recipe can't burn more than {} tokens",
+            MAX_FUSION_INPUTS
+        );
+        assert!(
+            recipe.mt_inputs.len() <= MAX_FUSION_MT_INPUTS,
+            "A recipe can't burn more than {} different multi tokens",
+            MAX_FUSION_MT_INPUTS
+        );
+        assert!(total_inputs > 0 || !recipe.mt_inputs.is_empty(), "A recipe must burn something");
+        for token_type_id in recipe.inputs.keys() {
+            assert!(self.token_types_by_id.get(token_type_id).is_some(), "No token type {}", token_type_id);
+        }
+        for (token_id, amount) in recipe.mt_inputs.iter() {
+            assert!(self.mt_tokens_by_id.get(token_id).is_some(), "No multi token {}", token_id);
+            assert!(amount.0 > 0, "Amount must be greater than 0");
+        }
+        assert!(self.token_types_by_id.get(&recipe.output).is_some(), "No token type {}", recipe.output);
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        //insert the recipe and make sure that it doesn't exist
+        assert!(
+            self.recipes_by_id.insert(&recipe_id, &recipe).is_none(),
+            "Recipe already exists"
+        );
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the owner attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    //remove a fusion recipe
+    pub fn remove_recipe(&mut self, recipe_id: RecipeId) {
+        self.assert_owner();
+        assert!(self.recipes_by_id.remove(&recipe_id).is_some(), "No recipe");
+    }
+
+    //get a specific fusion recipe
+    pub fn get_recipe(&self, recipe_id: RecipeId) -> Option<Recipe> {
+        self.recipes_by_id.get(&recipe_id)
+    }
+
+    //Query for fusion recipes using pagination
+    pub fn get_recipes(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<(RecipeId, Recipe)> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        self.recipes_by_id.iter()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements. If we didn't specify a limit, use 0
+            .take(limit.unwrap_or(0) as usize)
+            .collect()
+    }
+
+    /*
+        burn the passed in tokens and the recipe's multi token units and mint a token of the recipe's output type to
+        the caller. the passed in tokens must match the recipe's inputs exactly. The caller needs to attach
+        enough to cover any extra storage.
+    */
+    #[payable]
+    pub fn fuse(&mut self, recipe_id: RecipeId, token_ids: Vec<TokenId>) -> TokenId {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+
+        let recipe = self.recipes_by_id.get(&recipe_id).expect("No recipe");
+        let owner_id = env::predecessor_account_id();
+
+        //count how many tokens of each type were passed in and make sure it matches the recipe
+        let mut inputs: HashMap<TokenTypeId, u32> = HashMap::new();
+        for token_id in token_ids.iter() {
+            self.assert_valid(token_id);
+            let token_type_id = token_type_of(token_id).expect("Token has no type");
+            *inputs.entry(token_type_id).or_insert(0) += 1;
+        }
+        assert!(inputs == recipe.inputs, "Tokens don't match the recipe inputs");
+
+        //the inputs are burned, so they make the randomness differ between fusions of the same owner and recipe in a block
+        let mut salt = [owner_id.as_bytes(), recipe_id.as_bytes(), token_ids.join(":").as_bytes()].concat();
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        //burn the inputs. This panics if the caller doesn't own all of them.
+        for token_id in token_ids.iter() {
+            self.internal_burn(&owner_id, token_id);
+        }
+        if !token_ids.is_empty() {
+            self.internal_log_burn(&owner_id, token_ids, Some(format!("Fused with recipe {}", recipe_id)));
+        }
+
+        //burn the multi token units. This panics if the caller doesn't hold enough of them.
+        for (token_id, amount) in recipe.mt_inputs.iter() {
+            self.internal_mt_burn(&owner_id, token_id, amount.0);
+        }
+
+        //mint the output with randomized traits based on the type template
+        let output_type = self.token_types_by_id.get(&recipe.output).expect("No token type");
+        //fusions that only burn multi tokens are told apart by the number of the output token
+        salt.extend_from_slice(&output_type.minted.to_le_bytes());
+        let attributes = output_type
+            .attributes
+            .map(|template| randomize_attributes(&template, &salt));
+        let token_id = self.internal_mint_typed(&recipe.output, &owner_id, attributes);
+
+        //calculate the extra storage used by the fusion (if the contract released storage, nothing needs to be paid)
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+
+        token_id
+    }
+}
@@ -0,0 +1,88 @@
+use crate::*;
+
+//maximum number of token hashes that can be uploaded in a single call (GAS limits this)
+const MAX_HASHES_PER_CALL: usize = 100;
+//length in bytes of a sha256 hash
+const SHA256_HASH_LEN: usize = 32;
+
+//the sha256 hashes of the off-chain content a token points to
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenHashes {
+    pub media_hash: Base64VecU8, // Base64-encoded sha256 hash of the file referenced by `media`
+    pub reference_hash: Base64VecU8, // Base64-encoded sha256 hash of the JSON referenced by `reference`
+}
+
+#[near_bindgen]
+impl Contract {
+    /*
+        upload a page of token hashes. Can be called multiple times to upload the whole table.
+        tokens that are already minted get their metadata updated right away (reveal),
+        tokens minted later pick up their hashes in nft_mint.
+    */
+    #[payable]
+    pub fn set_token_hashes(&mut self, hashes: HashMap<TokenId, TokenHashes>) {
+        //only the owner can upload hashes
+        self.assert_owner();
+        //make sure we don't run out of GAS while processing the page
+        assert!(
+            hashes.len() <= MAX_HASHES_PER_CALL,
+            "Cannot upload more than {} hashes at once",
+            MAX_HASHES_PER_CALL
+        );
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        for (token_id, token_hashes) in hashes.iter() {
+            //make sure that both hashes look like sha256 hashes
+            assert_eq!(token_hashes.media_hash.0.len(), SHA256_HASH_LEN, "Invalid media hash for token {}", token_id);
+            assert_eq!(token_hashes.reference_hash.0.len(), SHA256_HASH_LEN, "Invalid reference hash for token {}", token_id);
+
+            self.token_hashes_by_id.insert(token_id, token_hashes);
+
+            //if the token has already been minted, we reveal the hashes in its metadata
+            if let Some(mut metadata) = self.token_metadata_by_id.get(token_id) {
+                metadata.media_hash = Some(token_hashes.media_hash.clone());
+                metadata.reference_hash = Some(token_hashes.reference_hash.clone());
+                self.token_metadata_by_id.insert(token_id, &metadata);
+            }
+        }
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the owner attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    //Query for the uploaded token hashes using pagination
+    pub fn get_token_hashes(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<(TokenId, TokenHashes)> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        self.token_hashes_by_id.iter()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements. If we didn't specify a limit, use 0
+            .take(limit.unwrap_or(0) as usize)
+            .collect()
+    }
+
+    //get the uploaded hashes for a specific token ID
+    pub fn nft_token_hashes(&self, token_id: TokenId) -> Option<TokenHashes> {
+        self.token_hashes_by_id.get(&token_id)
+    }
+
+    //check if the passed in hash matches the media hash stored on-chain for the token
+    pub fn nft_verify_media_hash(&self, token_id: TokenId, media_hash: Base64VecU8) -> bool {
+        let metadata = self.token_metadata_by_id.get(&token_id).expect("No token");
+        metadata.media_hash == Some(media_hash)
+    }
+
+    //check if the passed in hash matches the reference hash stored on-chain for the token
+    pub fn nft_verify_reference_hash(&self, token_id: TokenId, reference_hash: Base64VecU8) -> bool {
+        let metadata = self.token_metadata_by_id.get(&token_id).expect("No token");
+        metadata.reference_hash == Some(reference_hash)
+    }
+}
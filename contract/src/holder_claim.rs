@@ -0,0 +1,167 @@
+use crate::*;
+use near_sdk::{ext_contract, Gas, PromiseResult};
+
+const GAS_FOR_NFT_TOKEN: Gas = Gas(5_000_000_000_000);
+const GAS_FOR_RESOLVE_HOLDER_CLAIM: Gas = Gas(40_000_000_000_000);
+const NO_DEPOSIT: Balance = 0;
+//maximum number of tokens that can be claimed at once (GAS limits this)
+const MAX_HOLDER_CLAIM_COUNT: usize = 3;
+//storage deposit needed per claimed token. Anything that isn't used is refunded once the tokens are minted.
+const HOLDER_CLAIM_STORAGE_DEPOSIT: Balance = 20_000_000_000_000_000_000_000; // 0.02 NEAR
+
+//the parts of a token of the source collection that are needed to verify a claim
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SourceToken {
+    pub owner_id: AccountId,
+}
+
+#[ext_contract(ext_source_collection)]
+trait SourceCollection {
+    //get a token of the source collection. Returns None if the token doesn't exist.
+    fn nft_token(&self, token_id: TokenId) -> Option<SourceToken>;
+}
+
+#[ext_contract(ext_holder_claim_resolver)]
+trait HolderClaimResolver {
+    /*
+        resolves the lookup of the source tokens. A genesis token is minted to the claimer for every source
+        token they own that wasn't claimed for yet. Returns the minted token IDs.
+    */
+    fn resolve_holder_claim(&mut self, claimer_id: AccountId, source_token_ids: Vec<TokenId>) -> Vec<TokenId>;
+}
+
+#[near_bindgen]
+impl Contract {
+    //set the collection whose holders can claim a free genesis token per held token. Passing in None ends the claim.
+    pub fn set_holder_claim_collection(&mut self, collection_id: Option<AccountId>) {
+        self.assert_owner();
+        self.holder_claim_collection = collection_id;
+    }
+
+    //get the collection whose holders can claim a free genesis token per held token
+    pub fn get_holder_claim_collection(&self) -> Option<AccountId> {
+        self.holder_claim_collection.clone()
+    }
+
+    //check if a genesis token was already claimed for a token of the source collection
+    pub fn is_holder_claimed(&self, source_token_id: TokenId) -> bool {
+        match self.holder_claim_collection.as_ref() {
+            Some(collection_id) => self.holder_claimed_tokens.contains(&(collection_id.clone(), source_token_id)),
+            None => false,
+        }
+    }
+
+    /*
+        claim a free genesis token for every passed in token of the source collection the caller holds.
+        The owners of the source tokens are looked up on the source collection before minting. The caller pays
+        for the storage of the minted tokens with the attached deposit or their prepaid storage balance.
+    */
+    #[payable]
+    pub fn nft_holder_claim(&mut self, source_token_ids: Vec<TokenId>) -> Promise {
+        let collection_id = self.holder_claim_collection.clone().expect("No holder claim");
+        assert!(
+            !source_token_ids.is_empty() && source_token_ids.len() <= MAX_HOLDER_CLAIM_COUNT,
+            "Can claim between 1 and {} tokens at once",
+            MAX_HOLDER_CLAIM_COUNT
+        );
+        assert!(
+            self.genesis_tokens_minted + (source_token_ids.len() as u64) <= GENESIS_SUPPLY,
+            "Minting ended"
+        );
+        for (i, source_token_id) in source_token_ids.iter().enumerate() {
+            assert!(!source_token_ids[..i].contains(source_token_id), "Token {} is passed in twice", source_token_id);
+            assert!(
+                !self.holder_claimed_tokens.contains(&(collection_id.clone(), source_token_id.clone())),
+                "Token {} was already claimed for",
+                source_token_id
+            );
+        }
+
+        //make sure the storage of every token can be payed for before looking them up
+        let claimer_id = env::predecessor_account_id();
+        let storage_deposit = HOLDER_CLAIM_STORAGE_DEPOSIT * source_token_ids.len() as Balance;
+        let available = self
+            .storage_balance_of(claimer_id.clone())
+            .map(|storage_balance| storage_balance.available.0)
+            .unwrap_or(0);
+        assert!(
+            env::attached_deposit() + available >= storage_deposit,
+            "Must attach {} yoctoNEAR or prepay storage with storage_deposit",
+            storage_deposit
+        );
+
+        //look up all the source tokens at once
+        let lookup = source_token_ids
+            .iter()
+            .map(|source_token_id| {
+                ext_source_collection::nft_token(
+                    source_token_id.clone(),
+                    collection_id.clone(), //contract account to make the call to
+                    NO_DEPOSIT, //attached deposit
+                    GAS_FOR_NFT_TOKEN, //GAS attached to the call
+                )
+            })
+            .reduce(|lookup, promise| lookup.and(promise))
+            .unwrap();
+
+        //we then resolve the lookup on our own contract, passing on the deposit to pay for the storage
+        lookup.then(ext_holder_claim_resolver::resolve_holder_claim(
+            claimer_id,
+            source_token_ids,
+            env::current_account_id(), //contract account to make the call to
+            env::attached_deposit(), //attached deposit
+            GAS_FOR_RESOLVE_HOLDER_CLAIM, //GAS attached to the call
+        ))
+    }
+
+    #[private]
+    #[payable]
+    pub fn resolve_holder_claim(&mut self, claimer_id: AccountId, source_token_ids: Vec<TokenId>) -> Vec<TokenId> {
+        let collection_id = self.holder_claim_collection.clone().expect("No holder claim");
+        let now = env::block_timestamp() / 1000000;
+        let phase = self.sale_phase_at(now);
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let mut token_ids = vec![];
+        for (i, source_token_id) in source_token_ids.into_iter().enumerate() {
+            //only the tokens the claimer still holds count
+            let held = match env::promise_result(i as u64) {
+                PromiseResult::Successful(value) => near_sdk::serde_json::from_slice::<Option<SourceToken>>(&value)
+                    .ok()
+                    .flatten()
+                    .map(|source_token| source_token.owner_id == claimer_id)
+                    .unwrap_or(false),
+                _ => false,
+            };
+
+            //the token could have been claimed for while the lookup was running
+            let key = (collection_id.clone(), source_token_id);
+            let my_token_id = self.genesis_tokens_minted + 1;
+            if !held || my_token_id > GENESIS_SUPPLY || !self.holder_claimed_tokens.insert(&key) {
+                continue;
+            }
+
+            token_ids.push(self.internal_mint_genesis(&claimer_id, my_token_id, phase, now));
+        }
+
+        //nothing was minted, so the whole deposit is refunded
+        if token_ids.is_empty() {
+            if env::attached_deposit() > 0 {
+                Promise::new(claimer_id).transfer(env::attached_deposit());
+            }
+            return token_ids;
+        }
+
+        //calculate the storage used by the tokens and the claimed source tokens
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the claimer attached too much. If they didn't attach enough, draw the rest from their storage balance.
+        self.internal_pay_storage(&claimer_id, required_storage_in_bytes, 0);
+
+        self.internal_log_mint(&claimer_id, token_ids.clone());
+        token_ids
+    }
+}
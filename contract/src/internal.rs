@@ -7,33 +7,55 @@ pub(crate) fn royalty_to_payout(royalty_percentage: u32, amount_to_pay: Balance)
     U128(royalty_percentage as u128 * amount_to_pay / 10_000u128)
 }
 
-//calculate how many bytes the account ID is taking up
-pub(crate) fn bytes_for_approved_account_id(account_id: &AccountId) -> u64 {
-    // The extra 4 bytes are coming from Borsh serialization to store the length of the string.
-    account_id.as_str().len() as u64 + 4 + size_of::<u64>() as u64
+//get the price after taking off the discount in basis points
+pub(crate) fn discounted_price(price: Balance, discount: u32) -> Balance {
+    price - royalty_to_payout(discount, price).0
+}
+
+//calculates the payout of a sale given the owner of the token, its royalty and the sale price
+pub(crate) fn royalty_payout(
+    owner_id: &AccountId,
+    royalty: &HashMap<AccountId, u32>,
+    balance: Balance,
+    max_len_payout: u32,
+) -> Payout {
+    //keep track of the total perpetual royalties
+    let mut total_perpetual = 0;
+    //keep track of the payout object to send back
+    let mut payout_object = Payout {
+        payout: HashMap::new()
+    };
+
+    //make sure we're not paying out to too many people (GAS limits this)
+    assert!(royalty.len() as u32 <= max_len_payout, "Market cannot payout to that many receivers");
+
+    //go through each key and value in the royalty object
+    for (k, v) in royalty.iter() {
+        //only insert into the payout if the key isn't the token owner (we add their payout at the end)
+        if k != owner_id {
+            payout_object.payout.insert(k.clone(), royalty_to_payout(*v, balance));
+            total_perpetual += *v;
+        }
+    }
+
+    // payout to previous owner who gets 100% - total perpetual royalties
+    payout_object.payout.insert(owner_id.clone(), royalty_to_payout(10000 - total_perpetual, balance));
+
+    //return the payout object
+    payout_object
 }
 
-//refund the storage taken up by passed in approved account IDs and send the funds to the passed in account ID. 
-pub(crate) fn refund_approved_account_ids_iter<'a, I>(
-    account_id: AccountId,
-    approved_account_ids: I, //the approved account IDs must be passed in as an iterator
-) -> Promise
-where
-    I: Iterator<Item = &'a AccountId>,
-{
-    //get the storage total by going through and summing all the bytes for each approved account IDs
-    let storage_released: u64 = approved_account_ids.map(bytes_for_approved_account_id).sum();
-    //transfer the account the storage that is released
-    Promise::new(account_id).transfer(Balance::from(storage_released) * env::storage_byte_cost())
+//the royalty every token of the collection is minted with unless told otherwise
+pub(crate) fn default_royalty() -> HashMap<AccountId, u32> {
+    let mut royalty = HashMap::new();
+    royalty.insert("glorygames.near".to_string().try_into().unwrap(), 1000);
+    royalty
 }
 
-//refund a map of approved account IDs and send the funds to the passed in account ID
-pub(crate) fn refund_approved_account_ids(
-    account_id: AccountId,
-    approved_account_ids: &HashMap<AccountId, u64>,
-) -> Promise {
-    //call the refund_approved_account_ids_iter with the approved account IDs as keys
-    refund_approved_account_ids_iter(account_id, approved_account_ids.keys())
+//calculate how many bytes the account ID is taking up
+pub(crate) fn bytes_for_approved_account_id(account_id: &AccountId) -> u64 {
+    // The extra 4 bytes are coming from Borsh serialization to store the length of the string.
+    account_id.as_str().len() as u64 + 4 + size_of::<u64>() as u64
 }
 
 //used to generate a unique prefix in our storage collections (this is to avoid data collisions)
@@ -45,6 +67,30 @@ pub(crate) fn hash_account_id(account_id: &AccountId) -> CryptoHash {
     hash
 }
 
+//used to generate a unique prefix for the collections of a token type
+pub(crate) fn hash_token_type_id(token_type_id: &TokenTypeId) -> CryptoHash {
+    //get the default hash
+    let mut hash = CryptoHash::default();
+    //we hash the token type ID and return it
+    hash.copy_from_slice(&env::sha256(token_type_id.as_bytes()));
+    hash
+}
+
+//used to generate a unique prefix for the collections of a token
+pub(crate) fn hash_token_id(token_id: &TokenId) -> CryptoHash {
+    //get the default hash
+    let mut hash = CryptoHash::default();
+    //we hash the token ID and return it
+    hash.copy_from_slice(&env::sha256(token_id.as_bytes()));
+    hash
+}
+
+//get a pseudo random number from the block's random seed. The salt makes several draws in the same block differ.
+pub(crate) fn random_u64(salt: &[u8]) -> u64 {
+    let hash = env::sha256(&[env::random_seed().as_slice(), salt].concat());
+    u64::from_le_bytes(hash[..8].try_into().unwrap())
+}
+
 //used to make sure the user attached exactly 1 yoctoNEAR
 pub(crate) fn assert_one_yocto() {
     assert_eq!(
@@ -85,7 +131,39 @@ pub(crate) fn refund_deposit(storage_used: u64) {
     }
 }
 
+//refund the initial deposit minus the price of what was bought and the storage that was used up
+pub(crate) fn refund_deposit_with_price(storage_used: u64, price: Balance) {
+    //get how much it would cost to store the information on top of the price
+    let required_cost = env::storage_byte_cost() * Balance::from(storage_used) + price;
+    //get the attached deposit
+    let attached_deposit = env::attached_deposit();
+
+    //make sure that the attached deposit is greater than or equal to the required cost
+    assert!(
+        required_cost <= attached_deposit,
+        "Must attach {} yoctoNEAR to cover the price and storage",
+        required_cost,
+    );
+
+    //get the refund amount from the attached deposit - required cost
+    let refund = attached_deposit - required_cost;
+
+    //if the refund is greater than 1 yocto NEAR, we refund the predecessor that amount
+    if refund > 1 {
+        Promise::new(env::predecessor_account_id()).transfer(refund);
+    }
+}
+
 impl Contract {
+    //make sure that the caller of the function is the contract owner
+    pub(crate) fn assert_owner(&self) {
+        assert_eq!(
+            &env::predecessor_account_id(),
+            &self.owner_id,
+            "Owner's method"
+        );
+    }
+
     //add a token to the set of tokens an owner has
     pub(crate) fn internal_add_token_to_owner(
         &mut self,
@@ -137,6 +215,81 @@ impl Contract {
         }
     }
 
+    //burns a token owned by the owner_id (internal method and can't be called directly via CLI).
+    pub(crate) fn internal_burn(&mut self, owner_id: &AccountId, token_id: &TokenId) {
+        //get the token object by passing in the token_id
+        let token = self.tokens_by_id.get(token_id).expect("No token");
+        //make sure that the token is owned by the passed in owner
+        assert_eq!(&token.owner_id, owner_id, "Token should be owned by the sender");
+        //staked tokens have to be unstaked first
+        assert!(!self.is_staked(token_id), "Token is staked");
+        //auctioned tokens have to stay around until the auction is settled
+        assert!(!self.is_auctioned(token_id), "Token is being auctioned");
+
+        //we remove the token from its owner's set and from its type
+        self.internal_remove_token_from_owner(owner_id, token_id);
+        if let Some(token_type_id) = token_type_of(token_id) {
+            self.internal_remove_token_from_type(&token_type_id, token_id);
+        }
+
+        //we remove everything that is stored for the token
+        self.tokens_by_id.remove(token_id);
+        self.token_metadata_by_id.remove(token_id);
+        self.attributes_by_id.remove(token_id);
+        self.transfer_locks.remove(token_id);
+        self.expiring_tokens.remove(token_id);
+        self.internal_clear_user(owner_id, token_id);
+        self.internal_remove_listing(token_id);
+
+        //we refund the owner for releasing the storage used up by the approved account IDs
+        if !token.approved_account_ids.is_empty() {
+            self.internal_refund_approvals(owner_id.clone(), token_id, &token.approved_account_ids);
+        }
+    }
+
+    //remove the expiries of the passed in approvals of the token and return the storage that was released
+    pub(crate) fn internal_remove_approval_expiries(&mut self, token_id: &TokenId, approved_account_ids: &HashMap<AccountId, u64>) -> u64 {
+        let initial_storage_usage = env::storage_usage();
+        for approval_id in approved_account_ids.values() {
+            self.approval_expiries.remove(&(token_id.clone(), *approval_id));
+        }
+        initial_storage_usage - env::storage_usage()
+    }
+
+    //refund the storage taken up by the passed in approvals of the token and their expiries and send the funds to the passed in account ID
+    pub(crate) fn internal_refund_approvals(&mut self, account_id: AccountId, token_id: &TokenId, approved_account_ids: &HashMap<AccountId, u64>) {
+        let expiries_released = self.internal_remove_approval_expiries(token_id, approved_account_ids);
+        let storage_released = expiries_released + approved_account_ids.keys().map(bytes_for_approved_account_id).sum::<u64>();
+        if storage_released > 0 {
+            Promise::new(account_id).transfer(Balance::from(storage_released) * env::storage_byte_cost());
+        }
+    }
+
+    //log that the passed in tokens of the owner were burned
+    pub(crate) fn internal_log_burn(&self, owner_id: &AccountId, token_ids: Vec<TokenId>, memo: Option<String>) {
+        // Construct the burn log as per the events standard.
+        let nft_burn_log: EventLog = EventLog {
+            // Standard name ("nep171").
+            standard: NFT_STANDARD_NAME.to_string(),
+            // Version of the standard ("nft-1.0.0").
+            version: NFT_METADATA_SPEC.to_string(),
+            // The data related with the event stored in a vector.
+            event: EventLogVariant::NftBurn(vec![NftBurnLog {
+                // Owner of the tokens.
+                owner_id: owner_id.to_string(),
+                // The optional authorized account ID that burned the tokens on behalf of the owner.
+                authorized_id: None,
+                // Vector of token IDs that were burned.
+                token_ids,
+                // An optional memo to include.
+                memo,
+            }]),
+        };
+
+        // Log the serialized json.
+        env::log_str(&nft_burn_log.to_string());
+    }
+
     //transfers the NFT to the receiver_id (internal method and can't be called directly via CLI).
     pub(crate) fn internal_transfer(
         &mut self,
@@ -146,12 +299,36 @@ impl Contract {
         //we introduce an approval ID so that people with that approval ID can transfer the token
         approval_id: Option<u64>,
         memo: Option<String>,
+    ) -> Token {
+        let token = self.internal_transfer_unlogged(sender_id, receiver_id, token_id, approval_id);
+
+        // Default the authorized ID to be None for the logs.
+        let mut authorized_id = None;
+        //if the approval ID was provided, set the authorized ID equal to the sender
+        if approval_id.is_some() {
+            authorized_id = Some(sender_id.to_string());
+        }
+
+        self.internal_log_transfer(authorized_id, &token.owner_id, receiver_id, vec![token_id.to_string()], memo);
+
+        //return the preivous token object that was transferred.
+        token
+    }
+
+    //transfers the NFT to the receiver_id without logging the transfer, so that transfers can be logged together.
+    pub(crate) fn internal_transfer_unlogged(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        //we introduce an approval ID so that people with that approval ID can transfer the token
+        approval_id: Option<u64>,
     ) -> Token {
         //get the token object by passing in the token_id
         let token = self.tokens_by_id.get(token_id).expect("No token");
 
-        //if the sender doesn't equal the owner, we check if the sender is in the approval list
-		if sender_id != &token.owner_id {
+        //if the sender doesn't equal the owner or one of their operators, we check if the sender is in the approval list
+		if sender_id != &token.owner_id && !self.is_operator(&token.owner_id, sender_id) {
 			//if the token's approved account IDs doesn't contain the sender, we panic
 			if !token.approved_account_ids.contains_key(sender_id) {
 				env::panic_str("Unauthorized");
@@ -173,14 +350,62 @@ impl Contract {
 					actual_approval_id, enforced_approval_id,
 				);
 			}
+
+            //approvals can expire
+            assert!(
+                !self.is_approval_expired(token_id, token.approved_account_ids[sender_id]),
+                "Approval has expired"
+            );
 		}
 
+        //soulbound tokens are bound to the account holding them
+        assert!(!self.is_soulbound(token_id), "Token is soulbound and cannot be transferred");
+        //expired tokens can't change hands anymore
+        assert!(!self.is_expired(token_id), "Token has expired");
+        //tokens can be locked for some time after mint
+        if let Some(locked_until) = self.locked_until(token_id) {
+            env::panic_str(&format!("Token is locked for transfers until {}", locked_until));
+        }
+
+        //move the token to the receiver
+        self.internal_move_token_unlogged(&token, receiver_id, token_id);
+
+        //return the preivous token object that was transferred.
+        token
+    }
+
+    //moves the token to the receiver_id without checking who is allowed to do so (internal method and can't be called directly via CLI).
+    pub(crate) fn internal_move_token(
+        &mut self,
+        token: &Token,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        //the authorized ID for logging the transfer event
+        authorized_id: Option<String>,
+        memo: Option<String>,
+    ) {
+        self.internal_move_token_unlogged(token, receiver_id, token_id);
+        self.internal_log_transfer(authorized_id, &token.owner_id, receiver_id, vec![token_id.to_string()], memo);
+    }
+
+    //moves the token to the receiver_id without checking who is allowed to do so and without logging the transfer.
+    pub(crate) fn internal_move_token_unlogged(&mut self, token: &Token, receiver_id: &AccountId, token_id: &TokenId) {
+        //staked tokens are locked in the vault until they are unstaked
+        assert!(!self.is_staked(token_id), "Token is staked");
+        //auctioned tokens can only change hands when the auction is settled
+        assert!(!self.is_auctioned(token_id), "Token is being auctioned");
+
         //we make sure that the sender isn't sending the token to themselves
         assert_ne!(
             &token.owner_id, receiver_id,
             "The token owner and the receiver should be different"
         );
 
+        //the rental ends when the token changes hands
+        self.internal_clear_user(&token.owner_id, token_id);
+        //listings of the previous owner aren't valid anymore
+        self.internal_remove_listing(token_id);
+
         //we remove the token from it's current owner's set
         self.internal_remove_token_from_owner(&token.owner_id, token_id);
         //we then add the token to the receiver_id's set
@@ -197,19 +422,23 @@ impl Contract {
         };
         //insert that new token into the tokens_by_id, replacing the old entry 
         self.tokens_by_id.insert(token_id, &new_token);
+    }
 
+    //log that the passed in tokens were transferred from the old owner to the new owner
+    pub(crate) fn internal_log_transfer(
+        &self,
+        //the authorized ID for logging the transfer event
+        authorized_id: Option<String>,
+        old_owner_id: &AccountId,
+        new_owner_id: &AccountId,
+        token_ids: Vec<String>,
+        memo: Option<String>,
+    ) {
         //if there was some memo attached, we log it. 
         if let Some(memo) = memo.as_ref() {
             env::log_str(&format!("Memo: {}", memo).to_string());
         }
 
-        // Default the authorized ID to be None for the logs.
-        let mut authorized_id = None;
-        //if the approval ID was provided, set the authorized ID equal to the sender
-        if approval_id.is_some() {
-            authorized_id = Some(sender_id.to_string());
-        }
-
         // Construct the transfer log as per the events standard.
         let nft_transfer_log: EventLog = EventLog {
             // Standard name ("nep171").
@@ -221,11 +450,11 @@ impl Contract {
                 // The optional authorized account ID to transfer the token on behalf of the old owner.
                 authorized_id,
                 // The old owner's account ID.
-                old_owner_id: token.owner_id.to_string(),
+                old_owner_id: old_owner_id.to_string(),
                 // The account ID of the new owner of the token.
-                new_owner_id: receiver_id.to_string(),
+                new_owner_id: new_owner_id.to_string(),
                 // A vector containing the token IDs as strings.
-                token_ids: vec![token_id.to_string()],
+                token_ids,
                 // An optional memo to include.
                 memo,
             }]),
@@ -233,8 +462,5 @@ impl Contract {
 
         // Log the serialized json.
         env::log_str(&nft_transfer_log.to_string());
-        
-        //return the preivous token object that was transferred.
-        token
     }
 } 
\ No newline at end of file
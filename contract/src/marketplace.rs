@@ -0,0 +1,317 @@
+use crate::*;
+
+//maximum number of royalty receivers the market pays out to (GAS limits this)
+const MARKET_MAX_LEN_PAYOUT: u32 = 10;
+//the market fee can't be more than 10%
+const MAX_MARKET_FEE: u32 = 1_000;
+
+//a token that is up for sale on the contract
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Listing {
+    //owner of the token when it was listed
+    pub owner_id: AccountId,
+    //price in yoctoNEAR, or in the fungible token if there is one
+    pub price: Balance,
+    //time in milliseconds after which the token can't be bought anymore
+    pub expires: Option<u64>,
+    //the fungible token the price is in. None for NEAR.
+    pub ft_contract_id: Option<AccountId>,
+}
+
+//The Json listing is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonListing {
+    //token ID
+    pub token_id: TokenId,
+    //owner of the listed token
+    pub owner_id: AccountId,
+    //price in yoctoNEAR, or in the fungible token if there is one
+    pub price: U128,
+    //time in milliseconds after which the token can't be bought anymore
+    pub expires: Option<u64>,
+    //the fungible token the price is in. None for NEAR.
+    pub ft_contract_id: Option<AccountId>,
+}
+
+#[near_bindgen]
+impl Contract {
+    //set the fee in basis points that the contract owner takes from every sale (1000 is 10%)
+    pub fn set_market_fee(&mut self, market_fee: u32) {
+        self.assert_owner();
+        assert!(market_fee <= MAX_MARKET_FEE, "Market fee can't be more than {}", MAX_MARKET_FEE);
+        self.market_fee = market_fee;
+    }
+
+    //get the fee in basis points that the contract owner takes from every sale
+    pub fn get_market_fee(&self) -> u32 {
+        self.market_fee
+    }
+
+    /*
+        put a token of the caller up for sale for the passed in price, in NEAR or in a fungible token that is then payed with
+        ft_transfer_call. Listing a token again updates the price. The caller needs to attach enough to cover the storage.
+    */
+    #[payable]
+    pub fn list(&mut self, token_id: TokenId, price: U128, expires: Option<u64>, ft_contract_id: Option<AccountId>) {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+
+        let owner_id = env::predecessor_account_id();
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        assert_eq!(token.owner_id, owner_id, "Predecessor must be the token owner.");
+        assert!(u128::from(price) > 0, "Price must be greater than 0");
+        if let Some(expires) = expires {
+            assert!(expires > env::block_timestamp() / 1000000, "Listing must expire in the future");
+        }
+
+        //tokens that can't be transferred can't be sold either
+        assert!(!self.is_soulbound(&token_id), "Token is soulbound and cannot be listed");
+        assert!(!self.is_staked(&token_id), "Token is staked");
+        assert!(!self.is_auctioned(&token_id), "Token is being auctioned");
+        assert!(!self.is_expired(&token_id), "Token has expired");
+        if let Some(locked_until) = self.locked_until(&token_id) {
+            env::panic_str(&format!("Token is locked for transfers until {}", locked_until));
+        }
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        self.listings_by_id.insert(&token_id, &Listing {
+            owner_id: owner_id.clone(),
+            price: price.into(),
+            expires,
+            ft_contract_id: ft_contract_id.clone(),
+        });
+
+        self.internal_log_market(EventLogVariant::NftList(vec![NftListLog {
+            token_id,
+            owner_id: owner_id.to_string(),
+            price: price.0.to_string(),
+            expires,
+            ft_contract_id: ft_contract_id.map(|ft_contract_id| ft_contract_id.to_string()),
+        }]));
+
+        //calculate the extra storage used by the listing (updating a listing doesn't use any)
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the user attached too much. If they didn't attach enough, draw the rest from their storage balance.
+        self.internal_pay_storage(&owner_id, required_storage_in_bytes, 0);
+    }
+
+    //take a token of the caller off the market. The storage of the listing is refunded.
+    #[payable]
+    pub fn delist(&mut self, token_id: TokenId) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+
+        let listing = self.listings_by_id.get(&token_id).expect("Token is not listed");
+        assert_eq!(
+            listing.owner_id,
+            env::predecessor_account_id(),
+            "Only the owner of the listing can delist it"
+        );
+        self.internal_remove_listing(&token_id);
+
+        self.internal_log_market(EventLogVariant::NftDelist(vec![NftDelistLog {
+            token_id,
+            owner_id: listing.owner_id.to_string(),
+        }]));
+    }
+
+    /*
+        buy a listed token. The caller has to attach at least the price of the listing.
+        The seller and royalty receivers are payed out, the market fee goes to the contract owner and any excess is refunded.
+    */
+    #[payable]
+    pub fn buy(&mut self, token_id: TokenId) {
+        let buyer_id = env::predecessor_account_id();
+        let listing = self.listings_by_id.get(&token_id).expect("Token is not listed");
+        assert!(
+            listing.ft_contract_id.is_none(),
+            "Listing is priced in a fungible token and has to be bought with ft_transfer_call"
+        );
+
+        let attached_deposit = env::attached_deposit();
+        assert!(
+            attached_deposit >= listing.price,
+            "Must attach at least {} yoctoNEAR to buy the token",
+            listing.price
+        );
+
+        let previous_token = self.internal_buy_listed(&listing, &buyer_id, &token_id);
+
+        //pay out the seller, the royalty receivers and the market
+        let market_fee = self.internal_pay_out_sale(&previous_token, listing.price);
+
+        //refund the buyer if they attached more than the price
+        if attached_deposit > listing.price {
+            Promise::new(buyer_id.clone()).transfer(attached_deposit - listing.price);
+        }
+
+        self.internal_log_market(EventLogVariant::NftSale(vec![NftSaleLog {
+            token_id,
+            seller_id: previous_token.owner_id.to_string(),
+            buyer_id: buyer_id.to_string(),
+            price: listing.price.to_string(),
+            market_fee: market_fee.to_string(),
+            ft_contract_id: None,
+        }]));
+    }
+
+    //get the listing for a specific token ID
+    pub fn get_listing(&self, token_id: TokenId) -> Option<JsonListing> {
+        self.listings_by_id.get(&token_id).map(|listing| JsonListing {
+            token_id,
+            owner_id: listing.owner_id,
+            price: U128(listing.price),
+            expires: listing.expires,
+            ft_contract_id: listing.ft_contract_id,
+        })
+    }
+
+    //get the total number of listings on the market
+    pub fn get_listings_supply(&self) -> U128 {
+        U128(self.listings_by_id.len() as u128)
+    }
+
+    //Query for listings on the market (does not include expired listings)
+    pub fn get_listings(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonListing> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        let now = env::block_timestamp() / 1000000;
+
+        //iterate through the listings
+        self.listings_by_id.keys()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements in the vector. If we didn't specify a limit, use 50
+            .take(limit.unwrap_or(50) as usize)
+            //we'll map the token IDs which are strings into Json Listings
+            .filter_map(|token_id| self.get_listing(token_id))
+            //expired listings can't be bought anymore
+            .filter(|listing| listing.expires.map(|expires| now < expires).unwrap_or(true))
+            //since we turned the keys into an iterator, we need to turn it back into a vector to return
+            .collect()
+    }
+}
+
+impl Contract {
+    /*
+        buy a token listed in a fungible token for the buyer. The price is payed out in the fungible token
+        and the unused amount is returned.
+    */
+    pub(crate) fn internal_ft_buy(&mut self, ft_contract_id: &AccountId, buyer_id: &AccountId, token_id: TokenId, amount: Balance) -> Balance {
+        let listing = self.listings_by_id.get(&token_id).expect("Token is not listed");
+        assert_eq!(
+            listing.ft_contract_id.as_ref(),
+            Some(ft_contract_id),
+            "Listing is not priced in this token"
+        );
+        assert!(amount >= listing.price, "Must transfer at least {} to buy the token", listing.price);
+
+        //make sure everyone can be payed out before the token changes hands
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        self.assert_ft_payout_gas(&token, listing.price);
+
+        let previous_token = self.internal_buy_listed(&listing, buyer_id, &token_id);
+
+        //pay out the seller, the royalty receivers and the market
+        let market_fee = self.internal_pay_out_sale_ft(&previous_token, ft_contract_id, listing.price);
+
+        self.internal_log_market(EventLogVariant::NftSale(vec![NftSaleLog {
+            token_id,
+            seller_id: previous_token.owner_id.to_string(),
+            buyer_id: buyer_id.to_string(),
+            price: listing.price.to_string(),
+            market_fee: market_fee.to_string(),
+            ft_contract_id: Some(ft_contract_id.to_string()),
+        }]));
+
+        amount - listing.price
+    }
+
+    //transfer a listed token to the buyer on behalf of the seller and return the previous token. This also removes the listing.
+    fn internal_buy_listed(&mut self, listing: &Listing, buyer_id: &AccountId, token_id: &TokenId) -> Token {
+        assert_ne!(buyer_id, &listing.owner_id, "The owner can't buy their own token");
+        if let Some(expires) = listing.expires {
+            assert!(env::block_timestamp() / 1000000 < expires, "Listing has expired");
+        }
+
+        let previous_token = self.internal_transfer(
+            &listing.owner_id,
+            buyer_id,
+            token_id,
+            None,
+            Some("Bought on the market".to_string()),
+        );
+
+        //refund the previous token owner for the storage used up by the previous approved account IDs
+        if !previous_token.approved_account_ids.is_empty() {
+            self.internal_refund_approvals(
+                previous_token.owner_id.clone(),
+                token_id,
+                &previous_token.approved_account_ids,
+            );
+        }
+
+        previous_token
+    }
+
+    //take a token off the market and refund the storage of the listing to its owner (internal method and can't be called directly via CLI).
+    pub(crate) fn internal_remove_listing(&mut self, token_id: &TokenId) {
+        let initial_storage_usage = env::storage_usage();
+        if let Some(listing) = self.listings_by_id.remove(token_id) {
+            let storage_released = initial_storage_usage - env::storage_usage();
+            Promise::new(listing.owner_id).transfer(Balance::from(storage_released) * env::storage_byte_cost());
+        }
+    }
+
+    /*
+        pay out the price of a sold token. The contract owner takes the market fee and the rest is
+        payed out to the previous owner and royalty receivers. Returns the market fee.
+    */
+    pub(crate) fn internal_pay_out_sale(&self, previous_token: &Token, price: Balance) -> Balance {
+        let (payout, market_fee) = self.internal_sale_payout(previous_token, price);
+        for (account_id, amount) in payout.payout {
+            if amount.0 > 0 {
+                Promise::new(account_id).transfer(amount.0);
+            }
+        }
+
+        market_fee
+    }
+
+    //calculate the payout of a sale including the market fee for the contract owner. Returns the payout and the market fee.
+    pub(crate) fn internal_sale_payout(&self, previous_token: &Token, price: Balance) -> (Payout, Balance) {
+        let market_fee = royalty_to_payout(self.market_fee, price).0;
+        let mut payout = royalty_payout(
+            &previous_token.owner_id,
+            &previous_token.royalty,
+            price - market_fee,
+            MARKET_MAX_LEN_PAYOUT,
+        );
+
+        if market_fee > 0 {
+            let owner_payout = payout.payout.get(&self.owner_id).map(|amount| amount.0).unwrap_or(0);
+            payout.payout.insert(self.owner_id.clone(), U128(owner_payout + market_fee));
+        }
+
+        (payout, market_fee)
+    }
+
+    //log a market event
+    pub(crate) fn internal_log_market(&self, event: EventLogVariant) {
+        let market_log: EventLog = EventLog {
+            // Standard name ("glorygames").
+            standard: GAME_STANDARD_NAME.to_string(),
+            // Version of the game events ("1.0.0").
+            version: GAME_EVENT_VERSION.to_string(),
+            // The data related with the event stored in a vector.
+            event,
+        };
+
+        // Log the serialized json.
+        env::log_str(&market_log.to_string());
+    }
+}
@@ -7,6 +7,15 @@ pub struct Payout {
     pub payout: HashMap<AccountId, U128>,
 } 
 
+//the payout of a sale that was payed in a fungible token
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtPayout {
+    //the fungible token contract the amounts are in
+    pub ft_contract_id: AccountId,
+    pub payout: HashMap<AccountId, U128>,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct NFTContractMetadata {
@@ -19,7 +28,7 @@ pub struct NFTContractMetadata {
     pub reference_hash: Option<Base64VecU8>, // Base64-encoded sha256 hash of JSON from reference field. Required if `reference` is included.
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct TokenMetadata {
     pub title: Option<String>, // ex. "Arch Nemesis: Mail Carrier" or "Parcel #5055"
@@ -62,6 +71,10 @@ pub struct JsonToken {
     pub approved_account_ids: HashMap<AccountId, u64>,
     //keep track of the royalty percentages for the token in a hash map
     pub royalty: HashMap<AccountId, u32>,
+    //whether the token is bound to its owner and can't be transferred
+    pub soulbound: bool,
+    //time in milliseconds until which the token can't be transferred
+    pub locked_until: Option<u64>,
 }
 
 pub trait NonFungibleTokenMetadata {
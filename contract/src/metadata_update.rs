@@ -0,0 +1,126 @@
+use crate::*;
+
+//size limits for the fields that can be updated after mint
+const MAX_TITLE_LEN: usize = 256;
+const MAX_URL_LEN: usize = 512;
+const MAX_EXTRA_LEN: usize = 4096;
+
+//the set of token metadata fields that can be changed after the token was minted
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenMetadataPatch {
+    pub title: Option<String>, // new title of the token
+    pub media: Option<String>, // new URL to associated media
+    pub media_hash: Option<Base64VecU8>, // Base64-encoded sha256 hash of the new media. Cleared if `media` changes without it.
+    pub extra: Option<String>, // new extra data. Can be stringified JSON.
+    pub reference: Option<String>, // new URL to an off-chain JSON file with more info
+    pub reference_hash: Option<Base64VecU8>, // Base64-encoded sha256 hash of the new reference. Cleared if `reference` changes without it.
+}
+
+#[near_bindgen]
+impl Contract {
+    //allow an account to update token metadata
+    pub fn add_metadata_updater(&mut self, account_id: AccountId) {
+        self.assert_owner();
+        self.metadata_updaters.insert(&account_id);
+    }
+
+    //remove an account from the metadata updaters
+    pub fn remove_metadata_updater(&mut self, account_id: AccountId) {
+        self.assert_owner();
+        self.metadata_updaters.remove(&account_id);
+    }
+
+    //get the list of accounts that are allowed to update token metadata
+    pub fn get_metadata_updaters(&self) -> Vec<AccountId> {
+        self.metadata_updaters.to_vec()
+    }
+
+    //merge the passed in patch into the metadata of the token. Caller needs to attach enough to cover any extra storage.
+    #[payable]
+    pub fn nft_update_metadata(&mut self, token_id: TokenId, patch: TokenMetadataPatch) {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+        //make sure that the caller is allowed to update metadata
+        self.assert_metadata_updater();
+
+        let mut metadata = self.token_metadata_by_id.get(&token_id).expect("No token");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        if let Some(title) = patch.title {
+            assert!(title.len() <= MAX_TITLE_LEN, "Title cannot be longer than {} bytes", MAX_TITLE_LEN);
+            metadata.title = Some(title);
+        }
+        if let Some(media) = patch.media {
+            assert!(media.len() <= MAX_URL_LEN, "Media cannot be longer than {} bytes", MAX_URL_LEN);
+            //the old hash no longer describes the media, so it is replaced or cleared
+            metadata.media = Some(media);
+            metadata.media_hash = patch.media_hash;
+        } else if patch.media_hash.is_some() {
+            metadata.media_hash = patch.media_hash;
+        }
+        if let Some(extra) = patch.extra {
+            //tokens with game attributes keep them in extra, so it can only be changed through nft_set_attributes
+            assert!(
+                !self.attributes_by_id.contains_key(&token_id),
+                "Extra is managed by the token attributes"
+            );
+            assert!(extra.len() <= MAX_EXTRA_LEN, "Extra cannot be longer than {} bytes", MAX_EXTRA_LEN);
+            metadata.extra = Some(extra);
+        }
+        if let Some(reference) = patch.reference {
+            assert!(reference.len() <= MAX_URL_LEN, "Reference cannot be longer than {} bytes", MAX_URL_LEN);
+            //the old hash no longer describes the reference, so it is replaced or cleared
+            metadata.reference = Some(reference);
+            metadata.reference_hash = patch.reference_hash;
+        } else if patch.reference_hash.is_some() {
+            metadata.reference_hash = patch.reference_hash;
+        }
+
+        //set the time of the update in milliseconds
+        metadata.updated_at = Some(env::block_timestamp() / 1000000);
+        self.token_metadata_by_id.insert(&token_id, &metadata);
+
+        //calculate the extra storage used by the update (if the metadata shrunk, nothing needs to be paid)
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+
+        self.internal_log_metadata_update(vec![token_id]);
+    }
+}
+
+impl Contract {
+    //make sure that the caller is the owner or one of the metadata updaters
+    pub(crate) fn assert_metadata_updater(&self) {
+        let predecessor_account_id = env::predecessor_account_id();
+        assert!(
+            predecessor_account_id == self.owner_id || self.metadata_updaters.contains(&predecessor_account_id),
+            "Only metadata updaters can update token metadata"
+        );
+    }
+
+    //log that the metadata of the passed in tokens changed
+    pub(crate) fn internal_log_metadata_update(&self, token_ids: Vec<TokenId>) {
+        // Construct the metadata update log as per the events standard.
+        let nft_metadata_update_log: EventLog = EventLog {
+            // Standard name ("nep171").
+            standard: NFT_STANDARD_NAME.to_string(),
+            // Version of the standard ("nft-1.0.0").
+            version: NFT_METADATA_SPEC.to_string(),
+            // The data related with the event stored in a vector.
+            event: EventLogVariant::NftMetadataUpdate(vec![NftMetadataUpdateLog {
+                // Vector of token IDs that were updated.
+                token_ids,
+                // An optional memo to include.
+                memo: None,
+            }]),
+        };
+
+        // Log the serialized json.
+        env::log_str(&nft_metadata_update_log.to_string());
+    }
+}
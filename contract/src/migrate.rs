@@ -0,0 +1,98 @@
+use crate::*;
+
+//the state of the deployed contract, before token hashes and every later field were added to it
+#[derive(BorshDeserialize)]
+pub struct OldContract {
+    pub owner_id: AccountId,
+    pub tokens_per_owner: LookupMap<AccountId, UnorderedSet<TokenId>>,
+    pub tokens_by_id: LookupMap<TokenId, Token>,
+    pub token_metadata_by_id: UnorderedMap<TokenId, TokenMetadata>,
+    pub metadata: LazyOption<NFTContractMetadata>,
+    pub contributor_0: Vector<AccountId>,
+    pub contributor_4: Vector<AccountId>,
+    pub contributor_7: Vector<AccountId>,
+    pub contributor_10: Vector<AccountId>,
+    pub contributor_14: Vector<AccountId>,
+}
+
+#[near_bindgen]
+impl Contract {
+    /*
+        migrate the state of the deployed contract to the current layout. The tokens, their metadata and the
+        owner allowlist are kept, the removed allowlists are cleared and every field added since starts out empty.
+        Every field appended to the contract has to be initialized here as well.
+    */
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let mut old: OldContract = env::state_read().expect("No state to migrate");
+
+        //the removed allowlists release their storage
+        old.contributor_4.clear();
+        old.contributor_7.clear();
+        old.contributor_10.clear();
+        old.contributor_14.clear();
+
+        //every token minted so far is from the genesis collection
+        let genesis_tokens_minted = old.token_metadata_by_id.len();
+
+        Self {
+            //the deployed state is kept
+            owner_id: old.owner_id,
+            tokens_per_owner: old.tokens_per_owner,
+            tokens_by_id: old.tokens_by_id,
+            token_metadata_by_id: old.token_metadata_by_id,
+            metadata: old.metadata,
+            contributor_0: old.contributor_0,
+            //everything below was added after the deployed version
+            token_hashes_by_id: UnorderedMap::new(StorageKey::TokenHashesById.try_to_vec().unwrap()),
+            metadata_updaters: UnorderedSet::new(StorageKey::MetadataUpdaters.try_to_vec().unwrap()),
+            attributes_by_id: LookupMap::new(StorageKey::AttributesById.try_to_vec().unwrap()),
+            genesis_tokens_minted,
+            token_types_by_id: UnorderedMap::new(StorageKey::TokenTypesById.try_to_vec().unwrap()),
+            tokens_per_type: LookupMap::new(StorageKey::TokensPerType.try_to_vec().unwrap()),
+            token_types_locked: UnorderedSet::new(StorageKey::TokenTypesLocked.try_to_vec().unwrap()),
+            mt_tokens_by_id: UnorderedMap::new(StorageKey::MtTokensById.try_to_vec().unwrap()),
+            mt_balances_per_token: LookupMap::new(StorageKey::MtBalancesPerToken.try_to_vec().unwrap()),
+            recipes_by_id: UnorderedMap::new(StorageKey::RecipesById.try_to_vec().unwrap()),
+            breeding_config: None,
+            breeding_cooldowns: LookupMap::new(StorageKey::BreedingCooldowns.try_to_vec().unwrap()),
+            lineage_by_id: LookupMap::new(StorageKey::LineageById.try_to_vec().unwrap()),
+            staking_reward_rate: 1,
+            staked_tokens: LookupMap::new(StorageKey::StakedTokens.try_to_vec().unwrap()),
+            staked_per_owner: LookupMap::new(StorageKey::StakedPerOwner.try_to_vec().unwrap()),
+            reward_points: LookupMap::new(StorageKey::RewardPoints.try_to_vec().unwrap()),
+            soulbound_tokens: LookupSet::new(StorageKey::SoulboundTokens.try_to_vec().unwrap()),
+            transfer_locks_per_phase: LookupMap::new(StorageKey::TransferLocksPerPhase.try_to_vec().unwrap()),
+            transfer_locks: LookupMap::new(StorageKey::TransferLocks.try_to_vec().unwrap()),
+            expiring_tokens: UnorderedSet::new(StorageKey::ExpiringTokens.try_to_vec().unwrap()),
+            token_users: LookupMap::new(StorageKey::TokenUsers.try_to_vec().unwrap()),
+            listings_by_id: UnorderedMap::new(StorageKey::ListingsById.try_to_vec().unwrap()),
+            market_fee: 0,
+            offers_by_id: UnorderedMap::new(StorageKey::OffersById.try_to_vec().unwrap()),
+            offers_per_token: LookupMap::new(StorageKey::OffersPerToken.try_to_vec().unwrap()),
+            next_offer_id: 0,
+            auctions_by_id: UnorderedMap::new(StorageKey::AuctionsById.try_to_vec().unwrap()),
+            dutch_auction: None,
+            dutch_auction_purchases: LookupMap::new(StorageKey::DutchAuctionPurchases.try_to_vec().unwrap()),
+            dutch_auction_last_price: None,
+            raffle: None,
+            raffle_entrants: Vector::new(StorageKey::RaffleEntrants.try_to_vec().unwrap()),
+            raffle_entries: LookupMap::new(StorageKey::RaffleEntries.try_to_vec().unwrap()),
+            raffle_drawn: 0,
+            ft_mint_prices: UnorderedMap::new(StorageKey::FtMintPrices.try_to_vec().unwrap()),
+            unclaimed_ft_payouts: LookupMap::new(StorageKey::UnclaimedFtPayouts.try_to_vec().unwrap()),
+            referral_fee: 0,
+            referral_stats: UnorderedMap::new(StorageKey::ReferralStats.try_to_vec().unwrap()),
+            discount_codes: LookupMap::new(StorageKey::DiscountCodes.try_to_vec().unwrap()),
+            holder_claim_collection: None,
+            holder_claimed_tokens: LookupSet::new(StorageKey::HolderClaimedTokens.try_to_vec().unwrap()),
+            storage_balances: LookupMap::new(StorageKey::StorageBalances.try_to_vec().unwrap()),
+            approval_expiries: LookupMap::new(StorageKey::ApprovalExpiries.try_to_vec().unwrap()),
+            operators_per_owner: LookupMap::new(StorageKey::OperatorsPerOwner.try_to_vec().unwrap()),
+            staking_reward_per_unit: 0,
+            staking_reward_updated_at: env::block_height(),
+            ft_mint_proceeds: LookupMap::new(StorageKey::FtMintProceeds.try_to_vec().unwrap()),
+        }
+    }
+}
@@ -1,7 +1,37 @@
 use crate::*;
 
+const PUBSALE_TIME: u64 = 1663857000000; // 13th June 2022 05:00PM UTC
+
+const OWNERSALE_LIMIT: u128 = 538;
+const PUBSALE_LIMIT: u128 = 3;
+
+//number of tokens in the genesis collection
+pub(crate) const GENESIS_SUPPLY: u64 = 538;
+//price of a genesis token in the public sale
+const MINT_PRICE: Balance = 5_000_000_000_000_000_000_000_000; // 5 NEAR
+//part of the mint price that goes to Paras in basis points (1.75%)
+pub(crate) const PARAS_FEE: u32 = 175;
+//account the Paras fee is payed to
+pub(crate) const PARAS_ACCOUNT_ID: &str = "bd.paras.near";
+//attaching up to this much asks for a free mint that only pays for its storage
+const MAX_FREE_MINT_DEPOSIT: Balance = 100_000_000_000_000_000_000_000; // 0.1 NEAR
+
+//the phases of the genesis sale
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum SalePhase {
+    Raffle,
+    Public,
+    DutchAuction,
+}
+
 #[near_bindgen]
 impl Contract {
+    /*
+        mint the next genesis token to the caller. An optional referrer earns part of the price and
+        an optional discount code reduces it. Paid mints have the storage of the token deducted from the price,
+        free mints need to attach enough to cover it. Any excess is refunded.
+    */
     #[payable]
     pub fn nft_mint(
         &mut self,
@@ -10,212 +40,174 @@ impl Contract {
         // receiver_id: AccountId,
         //we add an optional parameter for perpetual royalties
         // perpetual_royalties: Option<HashMap<AccountId, u32>>,
+        referrer_id: Option<AccountId>,
+        discount_code: Option<String>,
     ) {
-        const OGSALE_TIME: u64 = 1663851600000; // 13th June 2022 04:00PM UTC
-        const APPRENTICESALE_TIME: u64 = 1663853400000; // 13th June 2022 05:00PM UTC
-        const WLSALE_TIME: u64 = 1663855200000; // 13th June 2022 05:00PM UTC
-        const PUBSALE_TIME: u64 = 1663857000000; // 13th June 2022 05:00PM UTC
-
-        const OWNERSALE_LIMIT: u128 = 538; // 13th June 2022 05:00PM UTC
-        const OGSALE_LIMIT: u128 = 3; // 13th June 2022 05:00PM UTC
-        const APPRENTICESALE_LIMIT: u128 = 2; // 13th June 2022 05:00PM UTC
-        const WLSALE_LIMIT: u128 = 1; // 13th June 2022 05:00PM UTC
-        const PUBSALE_LIMIT: u128 = 3; // 13th June 2022 05:00PM UTC
-
-        let my_token_id = self.token_metadata_by_id.len() + 1;
+        let my_token_id = self.genesis_tokens_minted + 1;
 
-        assert!(my_token_id < 539, "Minting ended");
+        assert!(my_token_id <= GENESIS_SUPPLY, "Minting ended");
 
         let account_id = env::predecessor_account_id();
 
         let amount = env::attached_deposit();
 
-        if amount == 0 {
-            let end = self.contributor_0.len();
-            let mut exist = false;
-            for i in 0..end {
-                if account_id == self.contributor_0.get(i).unwrap(){
-                    if self.nft_supply_for_owner(account_id.clone()).0 >= OWNERSALE_LIMIT {
-                        self.contributor_0.swap_remove(i);
-                        exist = false;
-                        break;
-                    }
-                    exist = true;
-                    break;
-                }
+        //make sure the account can mint right now and get the price it pays
+        let now = env::block_timestamp() / 1000000;
+        //owners of the collection mint for free as long as they only attach enough to cover the storage
+        let wants_free_mint = amount <= MAX_FREE_MINT_DEPOSIT;
+        let (phase, mut free_mint) = self.internal_assert_can_mint(&account_id, wants_free_mint, now);
+        //the public sale has free mints for everyone
+        if wants_free_mint && phase == Some(SalePhase::Public) {
+            free_mint = true;
+        }
+
+        //the discount code is only used up by paid mints
+        let discount = if free_mint {
+            0
+        } else {
+            discount_code.map(|code| self.internal_use_discount_code(&code)).unwrap_or(0)
+        };
+        //the price of the token and the part of it that is payed with the attached deposit
+        let (price, due) = if free_mint {
+            (0, 0)
+        } else {
+            self.internal_mint_price(&account_id, phase, amount, discount, now)
+        };
+
+        if price > 0 {
+            Promise::new(PARAS_ACCOUNT_ID.to_string().try_into().unwrap()).transfer(royalty_to_payout(PARAS_FEE, price).0);
+
+            //the referrer earns part of the price
+            if let Some(referrer_id) = referrer_id.as_ref() {
+                assert_ne!(referrer_id, &account_id, "You can't refer yourself");
+                self.internal_reward_referrer(referrer_id, price);
             }
-            if exist == false {
-                if (env::block_timestamp() / 1000000) < OGSALE_TIME {
-                    env::panic(b"You can't mint before NFT sale");           
-                } else if (env::block_timestamp() / 1000000) > OGSALE_TIME && (env::block_timestamp() / 1000000) < APPRENTICESALE_TIME {
-                    let end = self.contributor_4.len();
-                    let mut exist = false;
-                    for i in 0..end {
-                        if account_id == self.contributor_4.get(i).unwrap(){
-                            if self.nft_supply_for_owner(account_id.clone()).0 >= OGSALE_LIMIT {
-                                self.contributor_4.swap_remove(i);
-                                exist = false;
-                                break;
-                            }
-                            exist = true;
-                            break;
-                        }
-                    }
-                    if exist == false {
-                        env::panic(b"You are not OG user or You can't mint the more than 3");
-                    }
-                } else if (env::block_timestamp() / 1000000) > APPRENTICESALE_TIME && (env::block_timestamp() / 1000000) < WLSALE_TIME {
-                    let end = self.contributor_7.len();
-                    let mut exist = false;
-                    for i in 0..end {
-                        if account_id == self.contributor_7.get(i).unwrap(){
-                            if self.nft_supply_for_owner(account_id.clone()).0 >= APPRENTICESALE_LIMIT {
-                                self.contributor_7.swap_remove(i);
-                                exist = false;
-                                break;
-                            }
-                            exist = true;
-                            break;
-                        }
-                    }
-                    if exist == false {
-                        env::panic(b"You are not Apprentice user or You can't mint the more than 2");
-                    }
-                } else if (env::block_timestamp() / 1000000) > WLSALE_TIME && (env::block_timestamp() / 1000000) < PUBSALE_TIME {
-                    let end = self.contributor_10.len();
-                    let mut exist = false;
-                    for i in 0..end {
-                        if account_id == self.contributor_10.get(i).unwrap(){
-                            if self.nft_supply_for_owner(account_id.clone()).0 >= WLSALE_LIMIT {
-                                self.contributor_10.swap_remove(i);
-                                exist = false;
-                                break;
-                            }
-                            exist = true;
-                            break;
-                        }
-                    }
-                    let end1 = self.contributor_14.len();
-                    for i in 0..end1 {
-                        if account_id == self.contributor_14.get(i).unwrap(){
-                            if self.nft_supply_for_owner(account_id.clone()).0 >= WLSALE_LIMIT {
-                                self.contributor_14.swap_remove(i);
-                                exist = false;
-                                break;
-                            }
-                            exist = true;
-                            break;
-                        }
-                    }
-                    if exist == false {
-                        env::panic(b"You are not Whitelist user or You can't mint the more than 1");
-                    }
-                } else if (env::block_timestamp() / 1000000) > PUBSALE_TIME {
-                    let mut exist = false;
-                    if self.nft_supply_for_owner(account_id.clone()).0 >= PUBSALE_LIMIT {
-                        exist = false
-                    } else {
-                        exist = true;
-                    }
-                    if exist == false {
-                        env::panic(b"You can't mint the more than 3");
-                    }
+        }
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let token_id = self.internal_mint_genesis(&account_id, my_token_id, phase, now);
+
+        //calculate the storage used by the token, its metadata and the owner's set of tokens
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //paid mints have the storage deducted from the price. Mints whose price doesn't cover it pay for it on top.
+        let storage_cost = env::storage_byte_cost() * Balance::from(required_storage_in_bytes);
+        let required_cost = if price >= storage_cost { due } else { due + storage_cost };
+        assert!(
+            amount >= required_cost,
+            "Must attach {} yoctoNEAR to cover the price and storage",
+            required_cost
+        );
+
+        //refund any excess if the user attached too much
+        let refund = amount - required_cost;
+        if refund > 1 {
+            Promise::new(account_id.clone()).transfer(refund);
+        }
+
+        //keep track of what was payed during a Dutch auction
+        if phase == Some(SalePhase::DutchAuction) && !free_mint {
+            let schedule_price = self.dutch_auction_price_at(now);
+            self.internal_record_dutch_auction_purchase(&account_id, schedule_price, price, discount);
+        }
+
+        self.internal_log_mint(&account_id, vec![token_id]);
+    }
+    pub fn check_token(&self, id:TokenId)->bool{
+        self.tokens_by_id.contains_key(&id)
+    }
+
+    //set for how long tokens minted during a sale phase can't be transferred. Passing in None removes the lock.
+    pub fn set_phase_transfer_lock(&mut self, phase: SalePhase, duration: Option<u64>) {
+        self.assert_owner();
+        if let Some(duration) = duration {
+            self.transfer_locks_per_phase.insert(&phase, &duration);
+        } else {
+            self.transfer_locks_per_phase.remove(&phase);
+        }
+    }
+
+    //get for how long in milliseconds tokens minted during a sale phase can't be transferred
+    pub fn get_phase_transfer_lock(&self, phase: SalePhase) -> Option<u64> {
+        self.transfer_locks_per_phase.get(&phase)
+    }
+
+    //get the current phase of the genesis sale
+    pub fn get_sale_phase(&self) -> Option<SalePhase> {
+        self.sale_phase_at(env::block_timestamp() / 1000000)
+    }
+
+    //lift the transfer lock of the passed in tokens before it runs out
+    pub fn nft_lift_transfer_lock(&mut self, token_ids: Vec<TokenId>) {
+        self.assert_owner();
+        for token_id in token_ids.iter() {
+            self.transfer_locks.remove(token_id);
+        }
+    }
+
+    //get the number of tokens minted from the genesis collection
+    pub fn get_total_supply(&self) -> u64 {
+        self.genesis_tokens_minted
+    }
+}
+
+impl Contract {
+    /*
+        make sure the account is allowed to mint at the passed in time and return the current sale phase along with
+        whether the account mints for free. Owners of the collection can mint for free at any time if a free mint is
+        asked for, during the raffle only the winners can mint.
+    */
+    pub(crate) fn internal_assert_can_mint(&mut self, account_id: &AccountId, free: bool, now: u64) -> (Option<SalePhase>, bool) {
+        let minted = self.nft_supply_for_owner(account_id.clone()).0;
+        let phase = self.sale_phase_at(now);
+
+        if free && check_allowlist(&mut self.contributor_0, account_id, minted, OWNERSALE_LIMIT) {
+            return (phase, true);
+        }
+
+        match phase {
+            None => env::panic_str("You can't mint before NFT sale"),
+            Some(SalePhase::Raffle) => self.assert_raffle_winner(account_id),
+            Some(SalePhase::Public) | Some(SalePhase::DutchAuction) => {
+                if minted >= PUBSALE_LIMIT {
+                    env::panic_str("You can't mint the more than 3");
                 }
-                // env::panic(b"You can't mint the more than 2 in free");
             }
-        } else if amount == 5000000000000000000000000 {
-            if (env::block_timestamp() / 1000000) < OGSALE_TIME {
-                env::panic(b"You can't mint before NFT sale");           
-            } else if (env::block_timestamp() / 1000000) > OGSALE_TIME && (env::block_timestamp() / 1000000) < APPRENTICESALE_TIME {
-                let end = self.contributor_4.len();
-                let mut exist = false;
-                for i in 0..end {
-                    if account_id == self.contributor_4.get(i).unwrap(){
-                        if self.nft_supply_for_owner(account_id.clone()).0 >= OGSALE_LIMIT {
-                            self.contributor_4.swap_remove(i);
-                            exist = false;
-                            break;
-                        }
-                        exist = true;
-                        break;
-                    }
-                }
-                if exist == false {
-                    env::panic(b"You are not OG user or You can't mint the more than 3");
-                }
-            } else if (env::block_timestamp() / 1000000) > APPRENTICESALE_TIME && (env::block_timestamp() / 1000000) < WLSALE_TIME {
-                let end = self.contributor_7.len();
-                let mut exist = false;
-                for i in 0..end {
-                    if account_id == self.contributor_7.get(i).unwrap(){
-                        if self.nft_supply_for_owner(account_id.clone()).0 >= APPRENTICESALE_LIMIT {
-                            self.contributor_7.swap_remove(i);
-                            exist = false;
-                            break;
-                        }
-                        exist = true;
-                        break;
-                    }
-                }
-                if exist == false {
-                    env::panic(b"You are not Apprentice user or You can't mint the more than 2");
-                }
-            } else if (env::block_timestamp() / 1000000) > WLSALE_TIME && (env::block_timestamp() / 1000000) < PUBSALE_TIME {
-                let end = self.contributor_10.len();
-                let mut exist = false;
-                for i in 0..end {
-                    if account_id == self.contributor_10.get(i).unwrap(){
-                        if self.nft_supply_for_owner(account_id.clone()).0 >= WLSALE_LIMIT {
-                            self.contributor_10.swap_remove(i);
-                            exist = false;
-                            break;
-                        }
-                        exist = true;
-                        break;
-                    }
-                }
-                let end1 = self.contributor_14.len();
-                for i in 0..end1 {
-                    if account_id == self.contributor_14.get(i).unwrap(){
-                        if self.nft_supply_for_owner(account_id.clone()).0 >= WLSALE_LIMIT {
-                            self.contributor_14.swap_remove(i);
-                            exist = false;
-                            break;
-                        }
-                        exist = true;
-                        break;
-                    }
-                }
-                if exist == false {
-                    env::panic(b"You are not Whitelist user or You can't mint the more than 1");
-                }
-            } else if (env::block_timestamp() / 1000000) > PUBSALE_TIME {
-                let mut exist = false;
-                if self.nft_supply_for_owner(account_id.clone()).0 >= PUBSALE_LIMIT {
-                    exist = false
-                } else {
-                    exist = true;
-                }
-                if exist == false {
-                    env::panic(b"You can't mint the more than 3");
-                }
+        }
+
+        (phase, false)
+    }
+
+    /*
+        get the price the account pays in the passed in phase after taking off the discount in basis points, along with
+        the part of it that is payed with the attached deposit. Panics if the attached amount doesn't cover it.
+    */
+    pub(crate) fn internal_mint_price(&mut self, account_id: &AccountId, phase: Option<SalePhase>, amount: Balance, discount: u32, now: u64) -> (Balance, Balance) {
+        match phase {
+            Some(SalePhase::DutchAuction) => {
+                let price = discounted_price(self.dutch_auction_price_at(now), discount);
+                assert!(amount >= price, "Must attach at least {} yoctoNEAR to mint", price);
+                return (price, price);
             }
-        } else if amount != 5000000000000000000000000 {
-            env::panic(b"Require correct amount of Near attached");
+            Some(SalePhase::Raffle) => return self.internal_use_raffle_allocation(account_id, amount, discount),
+            _ => {}
         }
 
-        let mut token_id = my_token_id.to_string();
+        let price = discounted_price(MINT_PRICE, discount);
+        if amount < price {
+            env::panic_str("Require correct amount of Near attached");
+        }
+        (price, price)
+    }
 
-        //measure the initial storage being used on the contract
-        let initial_storage_usage = env::storage_usage();
+    //mint the genesis token with the passed in edition to the owner and return its ID
+    pub(crate) fn internal_mint_genesis(&mut self, owner_id: &AccountId, edition: u64, phase: Option<SalePhase>, now: u64) -> TokenId {
+        let token_id = edition.to_string();
 
         // create a royalty map to store in the token
-        let mut royalty = HashMap::new();
-        royalty.insert("glorygames.near".to_string().try_into().unwrap(), 1000);
-
-        if amount > 0 {
-            Promise::new("bd.paras.near".to_string().try_into().unwrap()).transfer(87500000000000000000000);
-        }
+        let royalty = default_royalty();
 
         // if perpetual royalties were passed into the function: 
         // if let Some(perpetual_royalties) = perpetual_royalties {
@@ -231,7 +223,7 @@ impl Contract {
         //specify the token struct that contains the owner ID 
         let token = Token {
             //set the owner ID equal to the receiver ID passed into the function
-            owner_id: account_id,
+            owner_id: owner_id.clone(),
             //we set the approved account IDs to the default value (an empty map)
             approved_account_ids: Default::default(),
             //the next approval ID is set to 0
@@ -246,25 +238,74 @@ impl Contract {
             "Token already exists"
         );
 
+        //get the hashes for the token if the owner already uploaded them
+        let token_hashes = self.token_hashes_by_id.get(&token_id);
+
         //insert the token ID and metadata
         self.token_metadata_by_id.insert(&token_id, &TokenMetadata{
             title: Some("The Glory Game #".to_owned() + token_id.as_ref()),
             description: Some("A 538 collection introducing the Glory Games lore and character consisting of PETs, Avatars, in-game NFT consumables and egg shards.".to_owned()),
             media: Some("https://glorygames.mypinata.cloud/ipfs/QmXaSNi8eVJR4nqNASufuHCyw3Y3qENBHitpGNdbwr4D49/".to_owned() + token_id.clone().as_ref() + ".mp4"),
-            media_hash: None,
+            media_hash: token_hashes.as_ref().map(|hashes| hashes.media_hash.clone()),
             copies: None,
-            issued_at: Some(env::block_timestamp() / 1000000),
+            issued_at: Some(now),
             expires_at: None,
             starts_at: None,
             updated_at: None,
             extra: None,
             reference: Some("https://glorygames.mypinata.cloud/ipfs/QmeFdQa1wtrAq4mVrhuKhZDLLnSF9FT1M6rX6ib91NWt7j/".to_owned() + token_id.clone().as_ref() + ".json"),
-            reference_hash: None
+            reference_hash: token_hashes.map(|hashes| hashes.reference_hash)
         });
 
         //call the internal method for adding the token to the owner
         self.internal_add_token_to_owner(&token.owner_id, &token_id);
 
+        //lock the token for transfers if the current phase asks for it
+        if let Some(lock_duration) = phase.and_then(|phase| self.transfer_locks_per_phase.get(&phase)) {
+            self.transfer_locks.insert(&token_id, &(now + lock_duration));
+        }
+
+        //keep track of how many tokens of the genesis collection were minted
+        self.genesis_tokens_minted += 1;
+
+        token_id
+    }
+
+    //get the phase of the genesis sale at the passed in time in milliseconds. Returns None before the sale starts.
+    pub(crate) fn sale_phase_at(&self, timestamp: u64) -> Option<SalePhase> {
+        //a configured Dutch auction replaces the fixed price public sale once it starts
+        if let Some(dutch_auction) = self.dutch_auction.as_ref() {
+            if timestamp >= dutch_auction.start_time {
+                return Some(SalePhase::DutchAuction);
+            }
+        }
+
+        //the winners of a raffle mint during its mint window and the public sale waits until it ends
+        if let Some(raffle) = self.raffle.as_ref() {
+            if timestamp >= raffle.mint_start && timestamp < raffle.mint_end {
+                return Some(SalePhase::Raffle);
+            }
+            if timestamp < raffle.mint_end {
+                return None;
+            }
+        }
+
+        if timestamp >= PUBSALE_TIME {
+            Some(SalePhase::Public)
+        } else {
+            None
+        }
+    }
+
+    //get the time in milliseconds until which the token can't be transferred. Returns None if the token isn't locked.
+    pub(crate) fn locked_until(&self, token_id: &TokenId) -> Option<u64> {
+        self.transfer_locks
+            .get(token_id)
+            .filter(|locked_until| *locked_until > env::block_timestamp() / 1000000)
+    }
+
+    //log that the passed in tokens were minted to the owner
+    pub(crate) fn internal_log_mint(&self, owner_id: &AccountId, token_ids: Vec<TokenId>) {
         // Construct the mint log as per the events standard.
         let nft_mint_log: EventLog = EventLog {
             // Standard name ("nep171").
@@ -274,9 +315,9 @@ impl Contract {
             // The data related with the event stored in a vector.
             event: EventLogVariant::NftMint(vec![NftMintLog {
                 // Owner of the token.
-                owner_id: token.owner_id.to_string(),
+                owner_id: owner_id.to_string(),
                 // Vector of token IDs that were minted.
-                token_ids: vec![token_id.to_string()],
+                token_ids,
                 // An optional memo to include.
                 memo: None,
             }]),
@@ -284,18 +325,22 @@ impl Contract {
 
         // Log the serialized json.
         env::log_str(&nft_mint_log.to_string());
-
-        //calculate the required storage which was the used - initial
-        // let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
-
-        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
-        // refund_deposit(required_storage_in_bytes);
-    }
-    pub fn check_token(&self, id:TokenId)->bool{
-        self.tokens_by_id.contains_key(&id)
     }
+}
 
-    pub fn get_total_supply(&self) -> u64 {
-        self.token_metadata_by_id.len()
+/*
+    check if the account is on the allowlist and hasn't minted the limit yet.
+    accounts that minted the limit are removed from the allowlist.
+*/
+fn check_allowlist(allowlist: &mut Vector<AccountId>, account_id: &AccountId, minted: u128, limit: u128) -> bool {
+    for i in 0..allowlist.len() {
+        if account_id == &allowlist.get(i).unwrap() {
+            if minted >= limit {
+                allowlist.swap_remove(i);
+                return false;
+            }
+            return true;
+        }
     }
-}
\ No newline at end of file
+    false
+}
@@ -0,0 +1,341 @@
+use crate::*;
+
+//a semi-fungible token that is held in quantities (consumables, egg shards)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MtToken {
+    //metadata shared by every unit of the token
+    pub metadata: TokenMetadata,
+    //total number of units in existence
+    pub supply: Balance,
+}
+
+pub trait MultiTokenCore {
+    //transfers an amount of a token to a receiver ID
+    fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        //approvals are part of the standard but aren't supported by this contract
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+    );
+
+    //transfers amounts of several tokens to a receiver ID
+    fn mt_batch_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        //approvals are part of the standard but aren't supported by this contract
+        approvals: Option<Vec<Option<(AccountId, u64)>>>,
+        memo: Option<String>,
+    );
+
+    //get the balance of an account for a given token
+    fn mt_balance_of(&self, account_id: AccountId, token_id: TokenId) -> U128;
+
+    //get the balances of an account for several tokens
+    fn mt_batch_balance_of(&self, account_id: AccountId, token_ids: Vec<TokenId>) -> Vec<U128>;
+
+    //get the total supply of a given token
+    fn mt_supply(&self, token_id: TokenId) -> Option<U128>;
+}
+
+#[near_bindgen]
+impl MultiTokenCore for Contract {
+    /*
+        implementation of the mt_transfer method. This transfers units of the token from the sender to the receiver.
+        The sender pays for the storage of the balance if the receiver didn't hold the token yet.
+    */
+    #[payable]
+    fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+    ) {
+        //assert at least one yocto for security reasons - this will cause a redirect to the NEAR wallet
+        assert_at_least_one_yocto();
+        assert!(approval.is_none(), "Approvals are not supported for multi tokens");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let sender_id = env::predecessor_account_id();
+        self.internal_mt_transfer(&sender_id, &receiver_id, &token_id, amount.into());
+
+        //calculate the storage used by new balances. Balances the sender emptied are removed and make up for it.
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the user attached too much. If they didn't attach enough, draw the rest from their storage balance.
+        self.internal_pay_storage(&sender_id, required_storage_in_bytes, 0);
+
+        self.internal_log_mt_transfer(&sender_id, &receiver_id, vec![token_id], vec![amount], memo);
+    }
+
+    /*
+        implementation of the mt_batch_transfer method. All transfers either succeed or fail together.
+        The sender pays for the storage of the balances of tokens the receiver didn't hold yet.
+    */
+    #[payable]
+    fn mt_batch_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        approvals: Option<Vec<Option<(AccountId, u64)>>>,
+        memo: Option<String>,
+    ) {
+        //assert at least one yocto for security reasons - this will cause a redirect to the NEAR wallet
+        assert_at_least_one_yocto();
+        assert!(
+            approvals.unwrap_or_default().iter().all(Option::is_none),
+            "Approvals are not supported for multi tokens"
+        );
+        assert!(!token_ids.is_empty(), "Nothing to transfer");
+        assert_eq!(token_ids.len(), amounts.len(), "Number of token IDs and amounts must match");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let sender_id = env::predecessor_account_id();
+        for (token_id, amount) in token_ids.iter().zip(amounts.iter()) {
+            self.internal_mt_transfer(&sender_id, &receiver_id, token_id, amount.0);
+        }
+
+        //calculate the storage used by new balances. Balances the sender emptied are removed and make up for it.
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the user attached too much. If they didn't attach enough, draw the rest from their storage balance.
+        self.internal_pay_storage(&sender_id, required_storage_in_bytes, 0);
+
+        self.internal_log_mt_transfer(&sender_id, &receiver_id, token_ids, amounts, memo);
+    }
+
+    //get the balance of an account for a given token
+    fn mt_balance_of(&self, account_id: AccountId, token_id: TokenId) -> U128 {
+        U128(self.internal_mt_balance_of(&account_id, &token_id))
+    }
+
+    //get the balances of an account for several tokens
+    fn mt_batch_balance_of(&self, account_id: AccountId, token_ids: Vec<TokenId>) -> Vec<U128> {
+        token_ids
+            .iter()
+            .map(|token_id| U128(self.internal_mt_balance_of(&account_id, token_id)))
+            .collect()
+    }
+
+    //get the total supply of a given token
+    fn mt_supply(&self, token_id: TokenId) -> Option<U128> {
+        self.mt_tokens_by_id.get(&token_id).map(|token| U128(token.supply))
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    //create a new multi token. The owner needs to attach enough to cover the storage.
+    #[payable]
+    pub fn mt_create(&mut self, token_id: TokenId, metadata: TokenMetadata) {
+        self.assert_owner();
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        //insert the token and make sure that it doesn't exist
+        assert!(
+            self.mt_tokens_by_id.insert(&token_id, &MtToken { metadata, supply: 0 }).is_none(),
+            "Token already exists"
+        );
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the owner attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    //mint units of a multi token to the receiver. The owner needs to attach enough to cover the storage.
+    #[payable]
+    pub fn mt_mint(&mut self, token_id: TokenId, receiver_id: AccountId, amount: U128) {
+        self.assert_owner();
+        assert!(amount.0 > 0, "Amount must be greater than 0");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let mut token = self.mt_tokens_by_id.get(&token_id).expect("No token");
+        token.supply += amount.0;
+        self.mt_tokens_by_id.insert(&token_id, &token);
+        self.internal_mt_deposit(&receiver_id, &token_id, amount.0);
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the owner attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+
+        self.internal_log_mt_mint(&receiver_id, vec![token_id], vec![amount]);
+    }
+
+    //burn units of a multi token held by the caller
+    #[payable]
+    pub fn mt_burn(&mut self, token_id: TokenId, amount: U128) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+
+        let owner_id = env::predecessor_account_id();
+        self.internal_mt_burn(&owner_id, &token_id, amount.0);
+    }
+
+    //get the metadata for a specific multi token
+    pub fn mt_metadata(&self, token_id: TokenId) -> Option<TokenMetadata> {
+        self.mt_tokens_by_id.get(&token_id).map(|token| token.metadata)
+    }
+}
+
+impl Contract {
+    //get the balance of an account for a given token (0 if the account doesn't hold any)
+    pub(crate) fn internal_mt_balance_of(&self, account_id: &AccountId, token_id: &TokenId) -> Balance {
+        self.mt_balances_per_token
+            .get(token_id)
+            .and_then(|balances| balances.get(account_id))
+            .unwrap_or(0)
+    }
+
+    //add units of a token to an account's balance
+    pub(crate) fn internal_mt_deposit(&mut self, account_id: &AccountId, token_id: &TokenId, amount: Balance) {
+        //get the balances for the given token
+        let mut balances = self.mt_balances_per_token.get(token_id).unwrap_or_else(|| {
+            //if the token doesn't have any holders, we create a new lookup map
+            LookupMap::new(
+                StorageKey::MtBalancesPerTokenInner {
+                    //we get a new unique prefix for the collection
+                    token_id_hash: hash_token_id(token_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+
+        let balance = balances.get(account_id).unwrap_or(0);
+        balances.insert(
+            account_id,
+            &balance.checked_add(amount).expect("Balance overflow"),
+        );
+        self.mt_balances_per_token.insert(token_id, &balances);
+    }
+
+    //remove units of a token from an account's balance
+    pub(crate) fn internal_mt_withdraw(&mut self, account_id: &AccountId, token_id: &TokenId, amount: Balance) {
+        let mut balances = self.mt_balances_per_token.get(token_id).expect("Not enough balance");
+        let balance = balances.get(account_id).unwrap_or(0);
+        assert!(balance >= amount, "Not enough balance");
+
+        //we don't keep empty balances around
+        if balance == amount {
+            balances.remove(account_id);
+        } else {
+            balances.insert(account_id, &(balance - amount));
+        }
+        self.mt_balances_per_token.insert(token_id, &balances);
+    }
+
+    //move units of a token between two accounts (internal method and can't be called directly via CLI).
+    pub(crate) fn internal_mt_transfer(
+        &mut self,
+        sender_id: &AccountId,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        amount: Balance,
+    ) {
+        assert_ne!(sender_id, receiver_id, "The sender and the receiver should be different");
+        assert!(amount > 0, "Amount must be greater than 0");
+        assert!(self.mt_tokens_by_id.get(token_id).is_some(), "No token");
+
+        self.internal_mt_withdraw(sender_id, token_id, amount);
+        self.internal_mt_deposit(receiver_id, token_id, amount);
+    }
+
+    //burn units of a token held by the owner and log the burn
+    pub(crate) fn internal_mt_burn(&mut self, owner_id: &AccountId, token_id: &TokenId, amount: Balance) {
+        assert!(amount > 0, "Amount must be greater than 0");
+
+        let mut token = self.mt_tokens_by_id.get(token_id).expect("No token");
+        self.internal_mt_withdraw(owner_id, token_id, amount);
+        token.supply -= amount;
+        self.mt_tokens_by_id.insert(token_id, &token);
+
+        // Construct the burn log as per the events standard.
+        let mt_burn_log: EventLog = EventLog {
+            // Standard name ("nep245").
+            standard: MT_STANDARD_NAME.to_string(),
+            // Version of the standard ("1.0.0").
+            version: MT_SPEC.to_string(),
+            // The data related with the event stored in a vector.
+            event: EventLogVariant::MtBurn(vec![MtBurnLog {
+                owner_id: owner_id.to_string(),
+                authorized_id: None,
+                token_ids: vec![token_id.to_string()],
+                amounts: vec![amount.to_string()],
+                memo: None,
+            }]),
+        };
+
+        // Log the serialized json.
+        env::log_str(&mt_burn_log.to_string());
+    }
+
+    //log that units of the passed in tokens were minted to the owner
+    pub(crate) fn internal_log_mt_mint(&self, owner_id: &AccountId, token_ids: Vec<TokenId>, amounts: Vec<U128>) {
+        // Construct the mint log as per the events standard.
+        let mt_mint_log: EventLog = EventLog {
+            // Standard name ("nep245").
+            standard: MT_STANDARD_NAME.to_string(),
+            // Version of the standard ("1.0.0").
+            version: MT_SPEC.to_string(),
+            // The data related with the event stored in a vector.
+            event: EventLogVariant::MtMint(vec![MtMintLog {
+                owner_id: owner_id.to_string(),
+                token_ids,
+                amounts: amounts.iter().map(|amount| amount.0.to_string()).collect(),
+                memo: None,
+            }]),
+        };
+
+        // Log the serialized json.
+        env::log_str(&mt_mint_log.to_string());
+    }
+
+    //log that units of the passed in tokens were transferred
+    fn internal_log_mt_transfer(
+        &self,
+        old_owner_id: &AccountId,
+        new_owner_id: &AccountId,
+        token_ids: Vec<TokenId>,
+        amounts: Vec<U128>,
+        memo: Option<String>,
+    ) {
+        // Construct the transfer log as per the events standard.
+        let mt_transfer_log: EventLog = EventLog {
+            // Standard name ("nep245").
+            standard: MT_STANDARD_NAME.to_string(),
+            // Version of the standard ("1.0.0").
+            version: MT_SPEC.to_string(),
+            // The data related with the event stored in a vector.
+            event: EventLogVariant::MtTransfer(vec![MtTransferLog {
+                authorized_id: None,
+                old_owner_id: old_owner_id.to_string(),
+                new_owner_id: new_owner_id.to_string(),
+                token_ids,
+                amounts: amounts.iter().map(|amount| amount.0.to_string()).collect(),
+                memo,
+            }]),
+        };
+
+        // Log the serialized json.
+        env::log_str(&mt_transfer_log.to_string());
+    }
+}
@@ -115,8 +115,9 @@ impl NonFungibleTokenCore for Contract {
         );
 
         //we refund the owner for releasing the storage used up by the approved account IDs
-        refund_approved_account_ids(
+        self.internal_refund_approvals(
             previous_token.owner_id.clone(),
+            &token_id,
             &previous_token.approved_account_ids,
         );
     }
@@ -201,6 +202,8 @@ impl NonFungibleTokenCore for Contract {
             let metadata = self.token_metadata_by_id.get(&token_id).unwrap();
             //we return the JsonToken (wrapped by Some since we return an option)
             Some(JsonToken {
+                soulbound: self.is_soulbound(&token_id),
+                locked_until: self.locked_until(&token_id),
                 token_id,
                 owner_id: token.owner_id,
                 metadata,
@@ -242,7 +245,7 @@ impl NonFungibleTokenResolver for Contract {
                         revert the original transfer and thus we can just return true since nothing went wrong.
                     */
                     //we refund the owner for releasing the storage used up by the approved account IDs
-                    refund_approved_account_ids(owner_id, &approved_account_ids);
+                    self.internal_refund_approvals(owner_id, &token_id, &approved_account_ids);
                     return true;
                 }
             }
@@ -252,7 +255,7 @@ impl NonFungibleTokenResolver for Contract {
         let mut token = if let Some(token) = self.tokens_by_id.get(&token_id) {
             if token.owner_id != receiver_id {
                 //we refund the owner for releasing the storage used up by the approved account IDs
-                refund_approved_account_ids(owner_id, &approved_account_ids);
+                self.internal_refund_approvals(owner_id, &token_id, &approved_account_ids);
                 // The token is not owner by the receiver anymore. Can't return it.
                 return true;
             }
@@ -260,7 +263,7 @@ impl NonFungibleTokenResolver for Contract {
         //if there isn't a token object, it was burned and so we return true
         } else {
             //we refund the owner for releasing the storage used up by the approved account IDs
-            refund_approved_account_ids(owner_id, &approved_account_ids);
+            self.internal_refund_approvals(owner_id, &token_id, &approved_account_ids);
             return true;
         };
 
@@ -273,7 +276,7 @@ impl NonFungibleTokenResolver for Contract {
         token.owner_id = owner_id.clone();
 
         //we refund the receiver any approved account IDs that they may have set on the token
-        refund_approved_account_ids(receiver_id.clone(), &token.approved_account_ids);
+        self.internal_refund_approvals(receiver_id.clone(), &token_id, &token.approved_account_ids);
         //reset the approved account IDs to what they were before the transfer
         token.approved_account_ids = approved_account_ids;
 
@@ -0,0 +1,319 @@
+use crate::*;
+
+pub type OfferId = u64;
+
+//NEAR escrowed on the contract by a buyer for one specific token or for any tokens of the collection
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Offer {
+    //account that made the offer and receives the tokens
+    pub buyer_id: AccountId,
+    //the token the offer is for. None for collection offers.
+    pub token_id: Option<TokenId>,
+    //price in yoctoNEAR the buyer pays per token
+    pub price: Balance,
+    //number of tokens the buyer still wants to buy
+    pub count: u64,
+    //time in milliseconds after which the offer can't be accepted anymore
+    pub expires: Option<u64>,
+    //yoctoNEAR the buyer payed for the storage of the offer
+    pub storage_deposit: Balance,
+}
+
+//The Json offer is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonOffer {
+    //offer ID
+    pub offer_id: OfferId,
+    //account that made the offer
+    pub buyer_id: AccountId,
+    //the token the offer is for. None for collection offers.
+    pub token_id: Option<TokenId>,
+    //price in yoctoNEAR the buyer pays per token
+    pub price: U128,
+    //number of tokens the buyer still wants to buy
+    pub count: u64,
+    //time in milliseconds after which the offer can't be accepted anymore
+    pub expires: Option<u64>,
+}
+
+#[near_bindgen]
+impl Contract {
+    /*
+        offer to buy a token, even if it isn't listed. The attached deposit minus the storage of the offer is
+        escrowed on the contract and is the price offered.
+    */
+    #[payable]
+    pub fn make_offer(&mut self, token_id: TokenId, expires: Option<u64>) -> OfferId {
+        let buyer_id = env::predecessor_account_id();
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        assert_ne!(token.owner_id, buyer_id, "The owner can't make an offer for their own token");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+        let offer_id = self.internal_add_offer(&buyer_id, Some(token_id), 0, 1, expires);
+
+        //the storage of the offer is drawn from the storage balance of the buyer if they prepaid enough.
+        //Otherwise it is payed out of the attached deposit. The rest of the attached deposit is the price.
+        let storage_deposit = env::storage_byte_cost() * Balance::from(env::storage_usage() - initial_storage_usage);
+        let attached_deposit = env::attached_deposit();
+        let price = if self.internal_draw_storage_balance(&buyer_id, storage_deposit).is_some() {
+            attached_deposit
+        } else {
+            assert!(
+                attached_deposit > storage_deposit,
+                "Must attach more than {} yoctoNEAR to cover storage or prepay storage with storage_deposit",
+                storage_deposit
+            );
+            attached_deposit - storage_deposit
+        };
+        assert!(price > 0, "Price must be greater than 0");
+
+        let mut offer = self.offers_by_id.get(&offer_id).unwrap();
+        offer.price = price;
+        offer.storage_deposit = storage_deposit;
+        self.offers_by_id.insert(&offer_id, &offer);
+
+        self.internal_log_offer(offer_id, &offer);
+        offer_id
+    }
+
+    /*
+        offer to buy `count` tokens of the collection for the passed in price each. The caller needs to attach
+        count * price plus enough to cover the storage. Any excess is refunded.
+    */
+    #[payable]
+    pub fn make_collection_offer(&mut self, count: u64, price: U128) -> OfferId {
+        let buyer_id = env::predecessor_account_id();
+        let price = u128::from(price);
+        assert!(count > 0, "Count must be greater than 0");
+        assert!(price > 0, "Price must be greater than 0");
+        let escrow = price.checked_mul(Balance::from(count)).expect("Offer is too large");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+        let offer_id = self.internal_add_offer(&buyer_id, None, price, count, None);
+
+        let storage_used = env::storage_usage() - initial_storage_usage;
+        let mut offer = self.offers_by_id.get(&offer_id).unwrap();
+        offer.storage_deposit = env::storage_byte_cost() * Balance::from(storage_used);
+        self.offers_by_id.insert(&offer_id, &offer);
+
+        //refund any excess if the user attached too much. If they didn't attach enough, draw the rest from their storage balance.
+        self.internal_pay_storage(&buyer_id, storage_used, escrow);
+
+        self.internal_log_offer(offer_id, &offer);
+        offer_id
+    }
+
+    /*
+        sell a token of the caller to the buyer of an offer. The escrowed price is payed out to the caller
+        and the royalty receivers, minus the market fee. Collection offers accept any token of the collection.
+    */
+    #[payable]
+    pub fn accept_offer(&mut self, offer_id: OfferId, token_id: TokenId) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+
+        let mut offer = self.offers_by_id.get(&offer_id).expect("No offer");
+        if let Some(offer_token_id) = offer.token_id.as_ref() {
+            assert_eq!(offer_token_id, &token_id, "The offer is for a different token");
+        }
+        if let Some(expires) = offer.expires {
+            assert!(env::block_timestamp() / 1000000 < expires, "Offer has expired");
+        }
+
+        //transfer the token of the caller to the buyer
+        let sender_id = env::predecessor_account_id();
+        let previous_token = self.internal_transfer(
+            &sender_id,
+            &offer.buyer_id,
+            &token_id,
+            None,
+            Some(format!("Sold with offer {}", offer_id)),
+        );
+
+        //refund the previous token owner for the storage used up by the previous approved account IDs
+        if !previous_token.approved_account_ids.is_empty() {
+            self.internal_refund_approvals(
+                previous_token.owner_id.clone(),
+                &token_id,
+                &previous_token.approved_account_ids,
+            );
+        }
+
+        //pay out the seller, the royalty receivers and the market from the escrow
+        let market_fee = self.internal_pay_out_sale(&previous_token, offer.price);
+
+        //the offer is done once the buyer got all the tokens they asked for
+        offer.count -= 1;
+        if offer.count == 0 {
+            self.internal_remove_offer(offer_id, &offer);
+            Promise::new(offer.buyer_id.clone()).transfer(offer.storage_deposit);
+        } else {
+            self.offers_by_id.insert(&offer_id, &offer);
+        }
+
+        self.internal_log_market(EventLogVariant::NftSale(vec![NftSaleLog {
+            token_id,
+            seller_id: previous_token.owner_id.to_string(),
+            buyer_id: offer.buyer_id.to_string(),
+            price: offer.price.to_string(),
+            market_fee: market_fee.to_string(),
+            ft_contract_id: None,
+        }]));
+    }
+
+    //cancel an offer of the caller. The remaining escrow and the storage deposit are refunded.
+    #[payable]
+    pub fn cancel_offer(&mut self, offer_id: OfferId) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+
+        let offer = self.offers_by_id.get(&offer_id).expect("No offer");
+        assert_eq!(
+            offer.buyer_id,
+            env::predecessor_account_id(),
+            "Only the buyer can cancel the offer"
+        );
+
+        self.internal_remove_offer(offer_id, &offer);
+        Promise::new(offer.buyer_id.clone())
+            .transfer(offer.price * Balance::from(offer.count) + offer.storage_deposit);
+
+        self.internal_log_market(EventLogVariant::NftOfferCancel(vec![NftOfferCancelLog {
+            offer_id,
+            buyer_id: offer.buyer_id.to_string(),
+        }]));
+    }
+
+    //get a specific offer
+    pub fn get_offer(&self, offer_id: OfferId) -> Option<JsonOffer> {
+        self.offers_by_id.get(&offer_id).map(|offer| JsonOffer {
+            offer_id,
+            buyer_id: offer.buyer_id,
+            token_id: offer.token_id,
+            price: U128(offer.price),
+            count: offer.count,
+            expires: offer.expires,
+        })
+    }
+
+    //Query for all the offers on the contract
+    pub fn get_offers(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonOffer> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        //iterate through the offer IDs
+        self.offers_by_id.keys()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements in the vector. If we didn't specify a limit, use 50
+            .take(limit.unwrap_or(50) as usize)
+            //we'll map the offer IDs into Json Offers
+            .filter_map(|offer_id| self.get_offer(offer_id))
+            //since we turned the keys into an iterator, we need to turn it back into a vector to return
+            .collect()
+    }
+
+    //get the offers made for a specific token (does not include collection offers)
+    pub fn get_offers_for_token(&self, token_id: TokenId, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonOffer> {
+        //get the set of offers for the passed in token
+        let offer_set = if let Some(offer_set) = self.offers_per_token.get(&token_id) {
+            offer_set
+        } else {
+            //if there is no set of offers, we'll simply return an empty vector.
+            return vec![];
+        };
+
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        //iterate through the offer IDs
+        offer_set.iter()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements in the vector. If we didn't specify a limit, use 50
+            .take(limit.unwrap_or(50) as usize)
+            //we'll map the offer IDs into Json Offers
+            .filter_map(|offer_id| self.get_offer(offer_id))
+            //since we turned the keys into an iterator, we need to turn it back into a vector to return
+            .collect()
+    }
+}
+
+impl Contract {
+    //store a new offer and return its ID
+    fn internal_add_offer(
+        &mut self,
+        buyer_id: &AccountId,
+        token_id: Option<TokenId>,
+        price: Balance,
+        count: u64,
+        expires: Option<u64>,
+    ) -> OfferId {
+        if let Some(expires) = expires {
+            assert!(expires > env::block_timestamp() / 1000000, "Offer must expire in the future");
+        }
+
+        let offer_id = self.next_offer_id;
+        self.next_offer_id += 1;
+
+        //offers for a specific token are also tracked per token
+        if let Some(token_id) = token_id.as_ref() {
+            let mut offer_set = self.offers_per_token.get(token_id).unwrap_or_else(|| {
+                //if the token doesn't have any offers, we create a new unordered set
+                UnorderedSet::new(
+                    StorageKey::OffersPerTokenInner {
+                        //we get a new unique prefix for the collection
+                        token_id_hash: hash_token_id(token_id),
+                    }
+                    .try_to_vec()
+                    .unwrap(),
+                )
+            });
+            offer_set.insert(&offer_id);
+            self.offers_per_token.insert(token_id, &offer_set);
+        }
+
+        self.offers_by_id.insert(&offer_id, &Offer {
+            buyer_id: buyer_id.clone(),
+            token_id,
+            price,
+            count,
+            expires,
+            storage_deposit: 0,
+        });
+
+        offer_id
+    }
+
+    //remove an offer from the contract
+    fn internal_remove_offer(&mut self, offer_id: OfferId, offer: &Offer) {
+        self.offers_by_id.remove(&offer_id);
+
+        if let Some(token_id) = offer.token_id.as_ref() {
+            let mut offer_set = self.offers_per_token.get(token_id).unwrap();
+            offer_set.remove(&offer_id);
+
+            //if the offer set is now empty, we remove the token from the offers_per_token collection
+            if offer_set.is_empty() {
+                self.offers_per_token.remove(token_id);
+            } else {
+                self.offers_per_token.insert(token_id, &offer_set);
+            }
+        }
+    }
+
+    //log that an offer was made
+    fn internal_log_offer(&self, offer_id: OfferId, offer: &Offer) {
+        self.internal_log_market(EventLogVariant::NftOffer(vec![NftOfferLog {
+            offer_id,
+            buyer_id: offer.buyer_id.to_string(),
+            token_id: offer.token_id.clone(),
+            price: offer.price.to_string(),
+            count: offer.count,
+            expires: offer.expires,
+        }]));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    /*
+        approve an operator to transfer all the tokens of the caller, including the ones they get later.
+        The caller needs to attach enough to cover the storage or have it prepaid with storage_deposit.
+    */
+    #[payable]
+    pub fn nft_approve_all(&mut self, operator_id: AccountId) {
+        //assert at least one yocto for security reasons - this will cause a redirect to the NEAR wallet
+        assert_at_least_one_yocto();
+        let owner_id = env::predecessor_account_id();
+        assert_ne!(owner_id, operator_id, "Can't approve yourself as an operator");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let mut operators = self.operators_per_owner.get(&owner_id).unwrap_or_else(|| {
+            //if the account doesn't have any operators, we create a new unordered set
+            UnorderedSet::new(
+                StorageKey::OperatorsPerOwnerInner {
+                    //we get a new unique prefix for the collection
+                    account_id_hash: hash_account_id(&owner_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        operators.insert(&operator_id);
+        self.operators_per_owner.insert(&owner_id, &operators);
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the user attached too much. If they didn't attach enough, draw the rest from their storage balance.
+        self.internal_pay_storage(&owner_id, required_storage_in_bytes, 0);
+    }
+
+    //revoke an operator from transferring the tokens of the caller
+    #[payable]
+    pub fn nft_revoke_operator(&mut self, operator_id: AccountId) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        let owner_id = env::predecessor_account_id();
+        let mut operators = self.operators_per_owner.get(&owner_id).expect("No operators");
+
+        let initial_storage_usage = env::storage_usage();
+        assert!(operators.remove(&operator_id), "Account is not an operator");
+        if operators.is_empty() {
+            self.operators_per_owner.remove(&owner_id);
+        } else {
+            self.operators_per_owner.insert(&owner_id, &operators);
+        }
+
+        //refund the storage released by the operator to the caller
+        let storage_released = initial_storage_usage - env::storage_usage();
+        Promise::new(owner_id).transfer(Balance::from(storage_released) * env::storage_byte_cost());
+    }
+
+    //revoke all operators from transferring the tokens of the caller
+    #[payable]
+    pub fn nft_revoke_all_operators(&mut self) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        let owner_id = env::predecessor_account_id();
+
+        //only revoke if the caller has any operators
+        let initial_storage_usage = env::storage_usage();
+        if let Some(mut operators) = self.operators_per_owner.remove(&owner_id) {
+            operators.clear();
+
+            //refund the storage released by the operators to the caller
+            let storage_released = initial_storage_usage - env::storage_usage();
+            Promise::new(owner_id).transfer(Balance::from(storage_released) * env::storage_byte_cost());
+        }
+    }
+
+    //check if an account is an operator of the owner
+    pub fn nft_is_approved_for_all(&self, owner_id: AccountId, operator_id: AccountId) -> bool {
+        self.is_operator(&owner_id, &operator_id)
+    }
+
+    //get the operators of an owner
+    pub fn nft_operators(&self, owner_id: AccountId) -> Vec<AccountId> {
+        self.operators_per_owner
+            .get(&owner_id)
+            .map(|operators| operators.to_vec())
+            .unwrap_or_default()
+    }
+}
+
+impl Contract {
+    //check if an account is an operator of the owner
+    pub(crate) fn is_operator(&self, owner_id: &AccountId, operator_id: &AccountId) -> bool {
+        self.operators_per_owner
+            .get(owner_id)
+            .map(|operators| operators.contains(operator_id))
+            .unwrap_or(false)
+    }
+}
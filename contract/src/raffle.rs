@@ -0,0 +1,271 @@
+use crate::*;
+
+//maximum number of winners draw_raffle picks in a single call (GAS limits this)
+const MAX_DRAW_BATCH: u64 = 100;
+
+//how the raffle for the genesis sale is run
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RaffleConfig {
+    //when accounts can start entering the raffle, Unix epoch in milliseconds
+    pub entry_start: u64,
+    //when the entry window closes and the raffle can be drawn, Unix epoch in milliseconds
+    pub entry_end: u64,
+    //when winners can start minting their allocation, Unix epoch in milliseconds
+    pub mint_start: u64,
+    //when the raffle mint ends and the public sale takes over, Unix epoch in milliseconds
+    pub mint_end: u64,
+    //yoctoNEAR every entrant escrows. Winners have it credited to their mints, everyone else can claim it back.
+    pub deposit: U128,
+    //number of accounts that win
+    pub winners: u64,
+    //number of tokens every winner can mint
+    pub allocation: u32,
+    //price in yoctoNEAR winners pay per token
+    pub price: U128,
+}
+
+//an account that entered the raffle
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct RaffleEntry {
+    //yoctoNEAR still escrowed for the account
+    pub deposit: Balance,
+    pub won: bool,
+    //number of tokens the account can still mint
+    pub allocation: u32,
+}
+
+//The Json raffle entry is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonRaffleEntry {
+    pub account_id: AccountId,
+    //yoctoNEAR still escrowed for the account
+    pub deposit: U128,
+    pub won: bool,
+    //number of tokens the account can still mint
+    pub allocation: u32,
+}
+
+#[near_bindgen]
+impl Contract {
+    //set how the raffle is run. It can't be changed once accounts entered it.
+    pub fn set_raffle(&mut self, config: Option<RaffleConfig>) {
+        self.assert_owner();
+        assert!(self.raffle_entrants.is_empty(), "Accounts already entered the raffle");
+        if let Some(config) = config.as_ref() {
+            assert!(config.entry_start < config.entry_end, "Entry window must end after it starts");
+            assert!(config.entry_end <= config.mint_start, "Mint window can't start before the entry window ends");
+            assert!(config.mint_start < config.mint_end, "Mint window must end after it starts");
+            assert!(config.winners > 0, "There must be at least one winner");
+            assert!(config.allocation > 0, "Winners must be able to mint at least one token");
+        }
+        self.raffle = config;
+    }
+
+    //get how the raffle is run
+    pub fn get_raffle(&self) -> Option<RaffleConfig> {
+        self.raffle.clone()
+    }
+
+    //enter the raffle. The caller needs to attach the raffle deposit plus enough to cover the storage.
+    #[payable]
+    pub fn enter_raffle(&mut self) {
+        let config = self.raffle.clone().expect("No raffle");
+        let now = env::block_timestamp() / 1000000;
+        assert!(
+            now >= config.entry_start && now < config.entry_end,
+            "The raffle is not open for entries"
+        );
+
+        let account_id = env::predecessor_account_id();
+        assert!(self.raffle_entries.get(&account_id).is_none(), "Already entered the raffle");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        self.raffle_entrants.push(&account_id);
+        self.raffle_entries.insert(&account_id, &RaffleEntry {
+            deposit: config.deposit.0,
+            won: false,
+            allocation: 0,
+        });
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess if the user attached too much. Panic if they didn't attach enough to cover the deposit and storage.
+        refund_deposit_with_price(required_storage_in_bytes, config.deposit.0);
+    }
+
+    /*
+        draw up to `batch` winners of the raffle once the entry window closed. Every call uses the random seed
+        of its block, so large raffles are drawn across blocks. Returns the number of winners drawn so far.
+    */
+    pub fn draw_raffle(&mut self, batch: Option<u64>) -> u64 {
+        self.assert_owner();
+        let config = self.raffle.clone().expect("No raffle");
+        assert!(
+            env::block_timestamp() / 1000000 >= config.entry_end,
+            "The raffle is still open for entries"
+        );
+
+        let entrants = self.raffle_entrants.len();
+        let total_winners = config.winners.min(entrants);
+        let batch_end = total_winners.min(self.raffle_drawn + batch.unwrap_or(MAX_DRAW_BATCH).min(MAX_DRAW_BATCH));
+        assert!(self.raffle_drawn < total_winners, "The raffle was already drawn");
+
+        //every winner is swapped to the front of the entrants, after the winners drawn before
+        let mut winners = vec![];
+        for i in self.raffle_drawn..batch_end {
+            let j = i + random_u64(&i.to_le_bytes()) % (entrants - i);
+            let winner_id = self.raffle_entrants.get(j).unwrap();
+            if j != i {
+                let entrant_id = self.raffle_entrants.replace(i, &winner_id);
+                self.raffle_entrants.replace(j, &entrant_id);
+            }
+
+            let mut entry = self.raffle_entries.get(&winner_id).unwrap();
+            entry.won = true;
+            entry.allocation = config.allocation;
+            self.raffle_entries.insert(&winner_id, &entry);
+            winners.push(winner_id.to_string());
+        }
+        self.raffle_drawn = batch_end;
+
+        self.internal_log_raffle_draw(winners);
+        self.raffle_drawn
+    }
+
+    //check if all the winners of the raffle were drawn
+    pub fn is_raffle_drawn(&self) -> bool {
+        match self.raffle.as_ref() {
+            Some(config) => {
+                env::block_timestamp() / 1000000 >= config.entry_end
+                    && self.raffle_drawn == config.winners.min(self.raffle_entrants.len())
+            }
+            None => false,
+        }
+    }
+
+    /*
+        get the deposit of the caller back if they didn't win the raffle, or what is left of it once a winner minted their
+        allocation, the raffle mint ended or the collection sold out
+    */
+    #[payable]
+    pub fn claim_refund(&mut self) -> U128 {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        assert!(self.is_raffle_drawn(), "The raffle hasn't been drawn yet");
+
+        let account_id = env::predecessor_account_id();
+        let mut entry = self.raffle_entries.get(&account_id).expect("Not entered in the raffle");
+        let mint_ended = self
+            .raffle
+            .as_ref()
+            .map(|config| env::block_timestamp() / 1000000 >= config.mint_end)
+            .unwrap_or(true);
+        assert!(
+            !entry.won || entry.allocation == 0 || mint_ended || self.genesis_tokens_minted >= GENESIS_SUPPLY,
+            "Winners have their deposit credited to their mints"
+        );
+
+        let refund = entry.deposit;
+        if refund > 0 {
+            entry.deposit = 0;
+            self.raffle_entries.insert(&account_id, &entry);
+            Promise::new(account_id).transfer(refund);
+        }
+        U128(refund)
+    }
+
+    //get the raffle entry of an account
+    pub fn get_raffle_entry(&self, account_id: AccountId) -> Option<JsonRaffleEntry> {
+        self.raffle_entries.get(&account_id).map(|entry| JsonRaffleEntry {
+            account_id,
+            deposit: U128(entry.deposit),
+            won: entry.won,
+            allocation: entry.allocation,
+        })
+    }
+
+    //get the number of accounts that entered the raffle
+    pub fn get_raffle_entrants_count(&self) -> u64 {
+        self.raffle_entrants.len()
+    }
+
+    //Query for the winners of the raffle drawn so far
+    pub fn get_raffle_winners(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonRaffleEntry> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        //the winners are at the front of the entrants
+        self.raffle_entrants.iter()
+            .take(self.raffle_drawn as usize)
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements in the vector. If we didn't specify a limit, use 50
+            .take(limit.unwrap_or(50) as usize)
+            //we'll map the account IDs into Json raffle entries
+            .filter_map(|account_id| self.get_raffle_entry(account_id))
+            //since we turned the keys into an iterator, we need to turn it back into a vector to return
+            .collect()
+    }
+}
+
+impl Contract {
+    //make sure the account won the raffle and can still mint
+    pub(crate) fn assert_raffle_winner(&self, account_id: &AccountId) {
+        assert!(self.is_raffle_drawn(), "The raffle hasn't been drawn yet");
+        let allocation = self
+            .raffle_entries
+            .get(account_id)
+            .filter(|entry| entry.won)
+            .map(|entry| entry.allocation)
+            .unwrap_or(0);
+        if allocation == 0 {
+            env::panic_str("You didn't win the raffle or You can't mint more than your allocation");
+        }
+    }
+
+    /*
+        use one mint of the winner's allocation and return the raffle price after taking off the discount, along with
+        the part of it that is payed with the attached amount. The deposit of the winner is credited to the price first.
+    */
+    pub(crate) fn internal_use_raffle_allocation(&mut self, account_id: &AccountId, amount: Balance, discount: u32) -> (Balance, Balance) {
+        let price = discounted_price(self.raffle.as_ref().expect("No raffle").price.0, discount);
+        let mut entry = self.raffle_entries.get(account_id).expect("Not entered in the raffle");
+
+        let credit = entry.deposit.min(price);
+        let due = price - credit;
+        assert!(amount >= due, "Must attach at least {} yoctoNEAR to mint", due);
+
+        entry.deposit -= credit;
+        self.raffle_entries.insert(account_id, &entry);
+        self.internal_take_raffle_allocation(account_id);
+
+        (price, due)
+    }
+
+    //use one mint of the winner's allocation
+    pub(crate) fn internal_take_raffle_allocation(&mut self, account_id: &AccountId) {
+        let mut entry = self.raffle_entries.get(account_id).expect("Not entered in the raffle");
+        entry.allocation -= 1;
+        self.raffle_entries.insert(account_id, &entry);
+    }
+
+    //log the winners drawn in a batch
+    fn internal_log_raffle_draw(&self, winners: Vec<String>) {
+        let raffle_log: EventLog = EventLog {
+            // Standard name ("glorygames").
+            standard: GAME_STANDARD_NAME.to_string(),
+            // Version of the game events ("1.0.0").
+            version: GAME_EVENT_VERSION.to_string(),
+            // The data related with the event stored in a vector.
+            event: EventLogVariant::RaffleDraw(vec![RaffleDrawLog { winners }]),
+        };
+
+        // Log the serialized json.
+        env::log_str(&raffle_log.to_string());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::*;
+use near_sdk::json_types::Base58CryptoHash;
+
+//maximum part of the mint price in basis points that referrers can earn (20%)
+const MAX_REFERRAL_FEE: u32 = 2000;
+
+//a discount code created by the owner. Only the sha256 hash of the code is stored.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DiscountCode {
+    //discount on the mint price in basis points (1000 is 10%)
+    pub discount: u32,
+    //number of mints the code can be used for. None for unlimited.
+    pub max_uses: Option<u32>,
+    //number of mints the code was used for
+    pub uses: u32,
+    //time in milliseconds after which the code can't be used anymore
+    pub expires: Option<u64>,
+}
+
+//The Json discount code is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonDiscountCode {
+    //sha256 hash of the code
+    pub code_hash: Base58CryptoHash,
+    //discount on the mint price in basis points
+    pub discount: u32,
+    //number of mints the code can be used for. None for unlimited.
+    pub max_uses: Option<u32>,
+    //number of mints the code was used for
+    pub uses: u32,
+    //time in milliseconds after which the code can't be used anymore
+    pub expires: Option<u64>,
+}
+
+//what an account earned by referring minters
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct ReferralStats {
+    //number of mints the account referred
+    pub referrals: u64,
+    //yoctoNEAR earned in total
+    pub earned: Balance,
+    //yoctoNEAR that wasn't claimed yet
+    pub unclaimed: Balance,
+}
+
+//The Json referral stats are what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonReferralStats {
+    pub account_id: AccountId,
+    //number of mints the account referred
+    pub referrals: u64,
+    //yoctoNEAR earned in total
+    pub earned: U128,
+    //yoctoNEAR that wasn't claimed yet
+    pub unclaimed: U128,
+}
+
+#[near_bindgen]
+impl Contract {
+    //set the part of the mint price in basis points that referrers earn (500 is 5%)
+    pub fn set_referral_fee(&mut self, referral_fee: u32) {
+        self.assert_owner();
+        assert!(referral_fee <= MAX_REFERRAL_FEE, "Referral fee can't be more than {}", MAX_REFERRAL_FEE);
+        self.referral_fee = referral_fee;
+    }
+
+    //get the part of the mint price in basis points that referrers earn
+    pub fn get_referral_fee(&self) -> u32 {
+        self.referral_fee
+    }
+
+    /*
+        add a discount code or replace the one with the same hash. Only the sha256 hash of the code is passed in,
+        so the code itself never shows up on chain until a minter uses it.
+    */
+    pub fn add_discount_code(&mut self, code_hash: Base58CryptoHash, discount: u32, max_uses: Option<u32>, expires: Option<u64>) {
+        self.assert_owner();
+        assert!(discount > 0 && discount <= 10_000, "Discount must be between 1 and 10000 basis points");
+        self.discount_codes.insert(&code_hash.into(), &DiscountCode {
+            discount,
+            max_uses,
+            uses: 0,
+            expires,
+        });
+    }
+
+    //remove a discount code so it can't be used anymore
+    pub fn remove_discount_code(&mut self, code_hash: Base58CryptoHash) {
+        self.assert_owner();
+        self.discount_codes.remove(&code_hash.into()).expect("No discount code");
+    }
+
+    //get a discount code by the sha256 hash of the code
+    pub fn get_discount_code(&self, code_hash: Base58CryptoHash) -> Option<JsonDiscountCode> {
+        self.discount_codes.get(&code_hash.into()).map(|code| JsonDiscountCode {
+            code_hash,
+            discount: code.discount,
+            max_uses: code.max_uses,
+            uses: code.uses,
+            expires: code.expires,
+        })
+    }
+
+    //claim the yoctoNEAR the caller earned by referring minters
+    #[payable]
+    pub fn claim_referral_rewards(&mut self) -> U128 {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+
+        let account_id = env::predecessor_account_id();
+        let mut stats = self.referral_stats.get(&account_id).expect("No referral rewards");
+        let rewards = stats.unclaimed;
+        assert!(rewards > 0, "No referral rewards");
+
+        stats.unclaimed = 0;
+        self.referral_stats.insert(&account_id, &stats);
+        Promise::new(account_id).transfer(rewards);
+        U128(rewards)
+    }
+
+    //get what an account earned by referring minters
+    pub fn get_referral_stats(&self, account_id: AccountId) -> Option<JsonReferralStats> {
+        self.referral_stats.get(&account_id).map(|stats| JsonReferralStats {
+            account_id,
+            referrals: stats.referrals,
+            earned: U128(stats.earned),
+            unclaimed: U128(stats.unclaimed),
+        })
+    }
+
+    //Query for the referral stats of all the accounts that referred minters
+    pub fn get_referrers(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonReferralStats> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        //iterate through the referrers
+        self.referral_stats.keys()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements in the vector. If we didn't specify a limit, use 50
+            .take(limit.unwrap_or(50) as usize)
+            //we'll map the account IDs into Json referral stats
+            .filter_map(|account_id| self.get_referral_stats(account_id))
+            //since we turned the keys into an iterator, we need to turn it back into a vector to return
+            .collect()
+    }
+}
+
+impl Contract {
+    //use a discount code for a mint and return the discount in basis points
+    pub(crate) fn internal_use_discount_code(&mut self, code: &str) -> u32 {
+        let code_hash: CryptoHash = env::sha256(code.as_bytes()).try_into().unwrap();
+        let mut discount_code = self.discount_codes.get(&code_hash).expect("Invalid discount code");
+        if let Some(expires) = discount_code.expires {
+            assert!(env::block_timestamp() / 1000000 < expires, "Discount code has expired");
+        }
+        if let Some(max_uses) = discount_code.max_uses {
+            assert!(discount_code.uses < max_uses, "Discount code was used up");
+        }
+
+        discount_code.uses += 1;
+        self.discount_codes.insert(&code_hash, &discount_code);
+        discount_code.discount
+    }
+
+    //credit the referrer their part of the mint price and return it
+    pub(crate) fn internal_reward_referrer(&mut self, referrer_id: &AccountId, price: Balance) -> Balance {
+        let reward = royalty_to_payout(self.referral_fee, price).0;
+        let mut stats = self.referral_stats.get(referrer_id).unwrap_or_default();
+        stats.referrals += 1;
+        stats.earned += reward;
+        stats.unclaimed += reward;
+        self.referral_stats.insert(referrer_id, &stats);
+        reward
+    }
+}
@@ -0,0 +1,99 @@
+use crate::*;
+
+//a temporary user of a token that is different from its owner
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TokenUser {
+    //account that is allowed to use the token in game
+    pub user_id: AccountId,
+    //time in milliseconds when the rental ends
+    pub expires: u64,
+}
+
+#[near_bindgen]
+impl Contract {
+    /*
+        lend the token to a user until the passed in time in milliseconds. The owner keeps the token.
+        passing in None as the user ends the current rental. The caller needs to attach enough to cover the storage.
+    */
+    #[payable]
+    pub fn nft_set_user(&mut self, token_id: TokenId, user_id: Option<AccountId>, expires: u64) {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        assert_eq!(
+            &env::predecessor_account_id(),
+            &token.owner_id,
+            "Predecessor must be the token owner."
+        );
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        //end the current rental if there is one
+        self.internal_clear_user(&token.owner_id, &token_id);
+
+        if let Some(user_id) = user_id {
+            assert!(expires > env::block_timestamp() / 1000000, "Rental must end in the future");
+            assert_ne!(user_id, token.owner_id, "The owner can't rent the token to themselves");
+            self.token_users.insert(&token_id, &TokenUser {
+                user_id: user_id.clone(),
+                expires,
+            });
+            self.internal_log_rental(EventLogVariant::NftRentalStart(vec![NftRentalLog {
+                token_id,
+                owner_id: token.owner_id.to_string(),
+                user_id: user_id.to_string(),
+                expires,
+            }]));
+        }
+
+        //calculate the extra storage used by the rental (if the contract released storage, nothing needs to be paid)
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+
+        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    //get the current user of the token. Returns None if the token isn't rented or the rental ended.
+    pub fn nft_user_of(&self, token_id: TokenId) -> Option<AccountId> {
+        self.token_users
+            .get(&token_id)
+            .filter(|token_user| token_user.expires > env::block_timestamp() / 1000000)
+            .map(|token_user| token_user.user_id)
+    }
+
+    //get the time in milliseconds when the rental of the token ends. Returns None if the token isn't rented.
+    pub fn nft_user_expires(&self, token_id: TokenId) -> Option<u64> {
+        self.token_users.get(&token_id).map(|token_user| token_user.expires)
+    }
+}
+
+impl Contract {
+    //end the rental of a token if there is one (internal method and can't be called directly via CLI).
+    pub(crate) fn internal_clear_user(&mut self, owner_id: &AccountId, token_id: &TokenId) {
+        if let Some(token_user) = self.token_users.remove(token_id) {
+            self.internal_log_rental(EventLogVariant::NftRentalEnd(vec![NftRentalLog {
+                token_id: token_id.to_string(),
+                owner_id: owner_id.to_string(),
+                user_id: token_user.user_id.to_string(),
+                expires: token_user.expires,
+            }]));
+        }
+    }
+
+    //log a rental event
+    fn internal_log_rental(&self, event: EventLogVariant) {
+        let rental_log: EventLog = EventLog {
+            // Standard name ("glorygames").
+            standard: GAME_STANDARD_NAME.to_string(),
+            // Version of the game events ("1.0.0").
+            version: GAME_EVENT_VERSION.to_string(),
+            // The data related with the event stored in a vector.
+            event,
+        };
+
+        // Log the serialized json.
+        env::log_str(&rental_log.to_string());
+    }
+}
@@ -24,39 +24,8 @@ impl NonFungibleTokenCore for Contract {
         //get the token object
 		let token = self.tokens_by_id.get(&token_id).expect("No token");
 
-        //get the owner of the token
-        let owner_id = token.owner_id;
-        //keep track of the total perpetual royalties
-        let mut total_perpetual = 0;
-        //get the u128 version of the passed in balance (which was U128 before)
-        let balance_u128 = u128::from(balance);
-		//keep track of the payout object to send back
-        let mut payout_object = Payout {
-            payout: HashMap::new()
-        };
-        //get the royalty object from token
-		let royalty = token.royalty;
-
-        //make sure we're not paying out to too many people (GAS limits this)
-		assert!(royalty.len() as u32 <= max_len_payout, "Market cannot payout to that many receivers");
-
-        //go through each key and value in the royalty object
-		for (k, v) in royalty.iter() {
-            //get the key
-			let key = k.clone();
-            //only insert into the payout if the key isn't the token owner (we add their payout at the end)
-			if key != owner_id {
-                //
-				payout_object.payout.insert(key, royalty_to_payout(*v, balance_u128));
-				total_perpetual += *v;
-			}
-		}
-
-		// payout to previous owner who gets 100% - total perpetual royalties
-		payout_object.payout.insert(owner_id, royalty_to_payout(10000 - total_perpetual, balance_u128));
-
-        //return the payout object
-		payout_object
+        //calculate the payout for the owner and the royalty receivers
+        royalty_payout(&token.owner_id, &token.royalty, u128::from(balance), max_len_payout)
 	}
 
     //transfers the token to the receiver ID and returns the payout object that should be payed given the passed in balance. 
@@ -72,6 +41,8 @@ impl NonFungibleTokenCore for Contract {
     ) -> Payout { 
         //assert that the user attached 1 yocto NEAR for security reasons
         assert_one_yocto();
+        //soulbound tokens can't be sold
+        assert!(!self.is_soulbound(&token_id), "Token is soulbound and cannot be transferred");
         //get the sender ID
         let sender_id = env::predecessor_account_id();
         //transfer the token to the passed in receiver and get the previous token object back
@@ -84,43 +55,50 @@ impl NonFungibleTokenCore for Contract {
         );
 
         //refund the previous token owner for the storage used up by the previous approved account IDs
-        refund_approved_account_ids(
+        self.internal_refund_approvals(
             previous_token.owner_id.clone(),
+            &token_id,
             &previous_token.approved_account_ids,
         );
 
-        //get the owner of the token
-        let owner_id = previous_token.owner_id;
-        //keep track of the total perpetual royalties
-        let mut total_perpetual = 0;
-        //get the u128 version of the passed in balance (which was U128 before)
-        let balance_u128 = u128::from(balance);
-		//keep track of the payout object to send back
-        let mut payout_object = Payout {
-            payout: HashMap::new()
-        };
-        //get the royalty object from token
-		let royalty = previous_token.royalty;
-
-        //make sure we're not paying out to too many people (GAS limits this)
-		assert!(royalty.len() as u32 <= max_len_payout, "Market cannot payout to that many receivers");
-
-        //go through each key and value in the royalty object
-		for (k, v) in royalty.iter() {
-            //get the key
-			let key = k.clone();
-            //only insert into the payout if the key isn't the token owner (we add their payout at the end)
-			if key != owner_id {
-                //
-				payout_object.payout.insert(key, royalty_to_payout(*v, balance_u128));
-				total_perpetual += *v;
-			}
-		}
+        //calculate the payout for the previous owner and the royalty receivers
+        royalty_payout(&previous_token.owner_id, &previous_token.royalty, u128::from(balance), max_len_payout)
+    }
+}
 
-		// payout to previous owner who gets 100% - total perpetual royalties
-		payout_object.payout.insert(owner_id, royalty_to_payout(10000 - total_perpetual, balance_u128));
+#[near_bindgen]
+impl Contract {
+    //calculates the payout for a token given the passed in balance of a fungible token. This is a view method
+    pub fn nft_payout_ft(&self, token_id: String, ft_contract_id: AccountId, balance: U128, max_len_payout: u32) -> FtPayout {
+        FtPayout {
+            ft_contract_id,
+            payout: self.nft_payout(token_id, balance, max_len_payout).payout,
+        }
+    }
 
-        //return the payout object
-		payout_object
+    //transfers the token to the receiver ID and returns the payout object that should be payed given the passed in balance of a fungible token.
+    #[payable]
+    pub fn nft_transfer_payout_ft(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        approval_id: u64,
+        ft_contract_id: AccountId,
+        balance: U128,
+        max_len_payout: u32,
+    ) -> FtPayout {
+        FtPayout {
+            payout: self
+                .nft_transfer_payout(
+                    receiver_id,
+                    token_id,
+                    approval_id,
+                    format!("Sold for {}", ft_contract_id),
+                    balance,
+                    max_len_payout,
+                )
+                .payout,
+            ft_contract_id,
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,59 @@
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    //mark a single token as soulbound (or not). Tokens of a soulbound type stay soulbound either way.
+    pub fn nft_set_soulbound(&mut self, token_id: TokenId, soulbound: bool) {
+        self.assert_owner();
+        assert!(self.tokens_by_id.contains_key(&token_id), "No token");
+
+        if soulbound {
+            self.soulbound_tokens.insert(&token_id);
+        } else {
+            self.soulbound_tokens.remove(&token_id);
+        }
+    }
+
+    //mark every token of a token type as soulbound (or not)
+    pub fn set_token_type_soulbound(&mut self, token_type_id: TokenTypeId, soulbound: bool) {
+        self.assert_owner();
+        let mut token_type = self.token_types_by_id.get(&token_type_id).expect("No token type");
+        token_type.soulbound = soulbound;
+        self.token_types_by_id.insert(&token_type_id, &token_type);
+    }
+
+    //check if a token is soulbound
+    pub fn nft_is_soulbound(&self, token_id: TokenId) -> bool {
+        self.is_soulbound(&token_id)
+    }
+
+    /*
+        move a token to another account on behalf of its owner, even if it is soulbound.
+        this is meant for recovering tokens of compromised or lost accounts.
+    */
+    #[payable]
+    pub fn nft_admin_recover(&mut self, token_id: TokenId, receiver_id: AccountId, memo: Option<String>) {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        self.assert_owner();
+
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        self.internal_move_token(&token, &receiver_id, &token_id, Some(self.owner_id.to_string()), memo);
+
+        //we refund the previous owner for releasing the storage used up by the approved account IDs
+        if !token.approved_account_ids.is_empty() {
+            self.internal_refund_approvals(token.owner_id, &token_id, &token.approved_account_ids);
+        }
+    }
+}
+
+impl Contract {
+    //check if a token is soulbound, either on its own or through its token type
+    pub(crate) fn is_soulbound(&self, token_id: &TokenId) -> bool {
+        self.soulbound_tokens.contains(token_id)
+            || token_type_of(token_id)
+                .and_then(|token_type_id| self.token_types_by_id.get(&token_type_id))
+                .map(|token_type| token_type.soulbound)
+                .unwrap_or(false)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::*;
+
+//maximum number of tokens an account can have staked at once (GAS limits claiming for all of them)
+const MAX_STAKED_PER_ACCOUNT: u64 = 100;
+
+//information about a staked token
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Stake {
+    //owner of the token when it was staked
+    pub owner_id: AccountId,
+    //when the token was staked, Unix epoch in milliseconds
+    pub staked_at: u64,
+    //reward per unit of the rarity multiplier up to which rewards have been accrued
+    pub reward_per_unit_paid: Balance,
+}
+
+//The Json stake is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonStake {
+    //token ID
+    pub token_id: TokenId,
+    //owner of the staked token
+    pub owner_id: AccountId,
+    //when the token was staked, Unix epoch in milliseconds
+    pub staked_at: u64,
+    //points accrued since the last claim
+    pub pending_rewards: U128,
+}
+
+#[near_bindgen]
+impl Contract {
+    //set how many points a staked token accrues per block before the rarity multiplier. Points accrued so far keep the old rate.
+    pub fn set_staking_reward_rate(&mut self, points_per_block: U128) {
+        self.assert_owner();
+        self.staking_reward_per_unit = self.internal_staking_reward_per_unit();
+        self.staking_reward_updated_at = env::block_height();
+        self.staking_reward_rate = points_per_block.into();
+    }
+
+    //get how many points a staked token accrues per block before the rarity multiplier
+    pub fn get_staking_reward_rate(&self) -> U128 {
+        U128(self.staking_reward_rate)
+    }
+
+    //lock the passed in tokens of the caller. The caller needs to attach enough to cover the storage.
+    #[payable]
+    pub fn stake(&mut self, token_ids: Vec<TokenId>) {
+        //assert at least one yocto for security reasons and to pay for storage
+        assert_at_least_one_yocto();
+        let owner_id = env::predecessor_account_id();
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let mut staked_set = self.staked_per_owner.get(&owner_id).unwrap_or_else(|| {
+            //if the account doesn't have any staked tokens, we create a new unordered set
+            UnorderedSet::new(
+                StorageKey::StakedPerOwnerInner {
+                    //we get a new unique prefix for the collection
+                    account_id_hash: hash_account_id(&owner_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+
+        let reward_per_unit = self.internal_staking_reward_per_unit();
+        for token_id in token_ids.iter() {
+            let token = self.tokens_by_id.get(token_id).expect("No token");
+            assert_eq!(token.owner_id, owner_id, "Only the token owner can stake it");
+            self.assert_valid(token_id);
+            assert!(!self.is_auctioned(token_id), "Token is being auctioned");
+
+            let stake = Stake {
+                owner_id: owner_id.clone(),
+                staked_at: env::block_timestamp() / 1000000,
+                reward_per_unit_paid: reward_per_unit,
+            };
+            assert!(self.staked_tokens.insert(token_id, &stake).is_none(), "Token {} is already staked", token_id);
+            staked_set.insert(token_id);
+        }
+
+        assert!(
+            staked_set.len() <= MAX_STAKED_PER_ACCOUNT,
+            "Cannot stake more than {} tokens",
+            MAX_STAKED_PER_ACCOUNT
+        );
+        self.staked_per_owner.insert(&owner_id, &staked_set);
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the user attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    //unlock the passed in tokens of the caller. Their pending rewards are claimed and the storage of the stakes is refunded.
+    #[payable]
+    pub fn unstake(&mut self, token_ids: Vec<TokenId>) -> U128 {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        let owner_id = env::predecessor_account_id();
+
+        let mut staked_set = self.staked_per_owner.get(&owner_id).expect("No staked tokens");
+        let initial_storage_usage = env::storage_usage();
+        let mut claimed = 0;
+        for token_id in token_ids.iter() {
+            assert!(staked_set.remove(token_id), "Token {} is not staked by the caller", token_id);
+            let stake = self.staked_tokens.remove(token_id).unwrap();
+            claimed += self.internal_pending_rewards(token_id, &stake);
+        }
+
+        //if the staked set is now empty, we remove the owner from the staked_per_owner collection
+        if staked_set.is_empty() {
+            self.staked_per_owner.remove(&owner_id);
+        } else {
+            self.staked_per_owner.insert(&owner_id, &staked_set);
+        }
+
+        let reward_points = self.internal_add_reward_points(&owner_id, claimed);
+
+        //refund the storage released by the stakes to the caller, less what their reward points took up
+        let storage_released = initial_storage_usage.saturating_sub(env::storage_usage());
+        if storage_released > 0 {
+            Promise::new(owner_id).transfer(Balance::from(storage_released) * env::storage_byte_cost());
+        }
+
+        reward_points
+    }
+
+    //move the rewards accrued by all the staked tokens of the caller to their reward points
+    #[payable]
+    pub fn claim(&mut self) -> U128 {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        let owner_id = env::predecessor_account_id();
+
+        let mut claimed = 0;
+        let reward_per_unit = self.internal_staking_reward_per_unit();
+        if let Some(staked_set) = self.staked_per_owner.get(&owner_id) {
+            for token_id in staked_set.iter() {
+                let mut stake = self.staked_tokens.get(&token_id).unwrap();
+                claimed += self.internal_pending_rewards(&token_id, &stake);
+                stake.reward_per_unit_paid = reward_per_unit;
+                self.staked_tokens.insert(&token_id, &stake);
+            }
+        }
+
+        self.internal_add_reward_points(&owner_id, claimed)
+    }
+
+    //get the stake for a specific token ID
+    pub fn get_stake(&self, token_id: TokenId) -> Option<JsonStake> {
+        self.staked_tokens.get(&token_id).map(|stake| JsonStake {
+            pending_rewards: U128(self.internal_pending_rewards(&token_id, &stake)),
+            token_id,
+            owner_id: stake.owner_id,
+            staked_at: stake.staked_at,
+        })
+    }
+
+    //get all the stakes for an account
+    pub fn get_stakes_for_owner(&self, account_id: AccountId) -> Vec<JsonStake> {
+        match self.staked_per_owner.get(&account_id) {
+            Some(staked_set) => staked_set
+                .iter()
+                .map(|token_id| self.get_stake(token_id).unwrap())
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    //get the reward points an account has claimed
+    pub fn get_reward_points(&self, account_id: AccountId) -> U128 {
+        U128(self.reward_points.get(&account_id).unwrap_or(0))
+    }
+}
+
+impl Contract {
+    //check if a token is currently staked
+    pub(crate) fn is_staked(&self, token_id: &TokenId) -> bool {
+        self.staked_tokens.contains_key(token_id)
+    }
+
+    //get the points a staked token accrued per unit of the rarity multiplier since staking began, up to the current block
+    fn internal_staking_reward_per_unit(&self) -> Balance {
+        let blocks = Balance::from(env::block_height() - self.staking_reward_updated_at);
+        self.staking_reward_per_unit + blocks * self.staking_reward_rate
+    }
+
+    //get the points a staked token accrued since rewards were last accrued
+    fn internal_pending_rewards(&self, token_id: &TokenId, stake: &Stake) -> Balance {
+        let reward_per_unit = self.internal_staking_reward_per_unit() - stake.reward_per_unit_paid;
+        //rarer tokens accrue more points
+        let multiplier = match self.attributes_by_id.get(token_id).map(|attributes| attributes.rarity) {
+            Some(Rarity::Uncommon) => 2,
+            Some(Rarity::Rare) => 3,
+            Some(Rarity::Epic) => 5,
+            Some(Rarity::Legendary) => 10,
+            Some(Rarity::Common) | None => 1,
+        };
+        reward_per_unit * multiplier
+    }
+
+    //add claimed points to an account and return its new balance
+    fn internal_add_reward_points(&mut self, account_id: &AccountId, points: Balance) -> U128 {
+        let balance = self.reward_points.get(account_id).unwrap_or(0) + points;
+        if points > 0 {
+            self.reward_points.insert(account_id, &balance);
+        }
+        U128(balance)
+    }
+}
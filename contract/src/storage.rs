@@ -0,0 +1,180 @@
+use crate::*;
+
+//bytes used by the storage balance of a registered account (longest account ID, balance and the record overhead)
+const ACCOUNT_STORAGE_BYTES: u64 = 125;
+
+//the storage balance of an account as per the storage management standard (NEP-145)
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalance {
+    pub total: U128,
+    pub available: U128,
+}
+
+//the bounds of a storage balance as per the storage management standard (NEP-145)
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalanceBounds {
+    pub min: U128,
+    pub max: Option<U128>,
+}
+
+#[near_bindgen]
+impl Contract {
+    /*
+        prepay storage for an account, or the caller if no account is passed in. Approvals, listings and offers
+        draw the storage they use from this balance when the attached deposit doesn't cover it. Registering an
+        account takes the minimum balance, which stays locked until the account unregisters.
+    */
+    #[payable]
+    pub fn storage_deposit(&mut self, account_id: Option<AccountId>, registration_only: Option<bool>) -> StorageBalance {
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let attached_deposit = env::attached_deposit();
+        let min_balance = self.storage_balance_bounds().min.0;
+
+        let balance = match self.storage_balances.get(&account_id) {
+            //registered accounts only get their balance topped up
+            Some(balance) => {
+                if registration_only.unwrap_or(false) {
+                    if attached_deposit > 0 {
+                        Promise::new(env::predecessor_account_id()).transfer(attached_deposit);
+                    }
+                    balance
+                } else {
+                    balance + attached_deposit
+                }
+            }
+            None => {
+                assert!(
+                    attached_deposit >= min_balance,
+                    "Must attach at least {} yoctoNEAR to register",
+                    min_balance
+                );
+                if registration_only.unwrap_or(false) {
+                    let refund = attached_deposit - min_balance;
+                    if refund > 0 {
+                        Promise::new(env::predecessor_account_id()).transfer(refund);
+                    }
+                    min_balance
+                } else {
+                    attached_deposit
+                }
+            }
+        };
+
+        self.storage_balances.insert(&account_id, &balance);
+        self.internal_storage_balance(balance)
+    }
+
+    //withdraw the available storage balance of the caller, or the passed in amount of it
+    #[payable]
+    pub fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+
+        let account_id = env::predecessor_account_id();
+        let balance = self.storage_balances.get(&account_id).expect("Account is not registered");
+        let available = balance - self.storage_balance_bounds().min.0;
+        let amount = amount.map(|amount| amount.0).unwrap_or(available);
+        assert!(amount <= available, "Can't withdraw more than the available {} yoctoNEAR", available);
+
+        let balance = balance - amount;
+        self.storage_balances.insert(&account_id, &balance);
+        if amount > 0 {
+            Promise::new(account_id).transfer(amount);
+        }
+        self.internal_storage_balance(balance)
+    }
+
+    /*
+        unregister the caller and refund their whole storage balance. Storage already used by approvals,
+        listings and offers was payed for, so nothing stops an account from unregistering.
+    */
+    #[payable]
+    pub fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        //assert that the user attached exactly 1 yoctoNEAR for security reasons
+        assert_one_yocto();
+        //nothing but the balance itself is tied to the registration, so forcing makes no difference
+        let _ = force;
+
+        let account_id = env::predecessor_account_id();
+        match self.storage_balances.remove(&account_id) {
+            Some(balance) => {
+                Promise::new(account_id).transfer(balance);
+                true
+            }
+            None => false,
+        }
+    }
+
+    //get the storage balance of an account. Returns None if the account isn't registered.
+    pub fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.storage_balances
+            .get(&account_id)
+            .map(|balance| self.internal_storage_balance(balance))
+    }
+
+    //get the minimum balance needed to register an account. There is no maximum.
+    pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds {
+            min: U128(env::storage_byte_cost() * Balance::from(ACCOUNT_STORAGE_BYTES)),
+            max: None,
+        }
+    }
+}
+
+impl Contract {
+    /*
+        pay for the storage used by the account and the price out of the attached deposit and refund any excess to the account.
+        The price always has to be attached. If the rest of the attached deposit doesn't cover the storage,
+        what is missing is drawn from the prepaid storage balance of the account.
+    */
+    pub(crate) fn internal_pay_storage(&mut self, account_id: &AccountId, storage_used: u64, price: Balance) {
+        //get the attached deposit
+        let attached_deposit = env::attached_deposit();
+        assert!(attached_deposit >= price, "Must attach at least {} yoctoNEAR", price);
+
+        //get how much it would cost to store the information
+        let storage_cost = env::storage_byte_cost() * Balance::from(storage_used);
+        let deposit_left = attached_deposit - price;
+
+        if deposit_left >= storage_cost {
+            //if the refund is greater than 1 yocto NEAR, we refund the account that amount
+            let refund = deposit_left - storage_cost;
+            if refund > 1 {
+                Promise::new(account_id.clone()).transfer(refund);
+            }
+            return;
+        }
+
+        //draw what is missing for the storage from the storage balance
+        let missing = storage_cost - deposit_left;
+        self.internal_draw_storage_balance(account_id, missing).unwrap_or_else(|| {
+            env::panic_str(&format!(
+                "Must attach {} yoctoNEAR or prepay storage with storage_deposit",
+                storage_cost + price
+            ))
+        });
+    }
+
+    //take the amount out of the available storage balance of the account. Returns None if there isn't enough.
+    pub(crate) fn internal_draw_storage_balance(&mut self, account_id: &AccountId, amount: Balance) -> Option<Balance> {
+        let balance = self.storage_balances.get(account_id)?;
+        let available = balance - self.storage_balance_bounds().min.0;
+        if amount > available {
+            return None;
+        }
+
+        let balance = balance - amount;
+        self.storage_balances.insert(account_id, &balance);
+        Some(balance)
+    }
+
+    //the storage balance in the format of the standard. The minimum balance isn't available.
+    fn internal_storage_balance(&self, balance: Balance) -> StorageBalance {
+        StorageBalance {
+            total: U128(balance),
+            available: U128(balance - self.storage_balance_bounds().min.0),
+        }
+    }
+}
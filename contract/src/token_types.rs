@@ -0,0 +1,353 @@
+use crate::*;
+use crate::nft_core::NonFungibleTokenCore;
+
+//maximum length of a token type ID
+const MAX_TOKEN_TYPE_ID_LEN: usize = 64;
+//separator between the token type ID and the edition number in a token ID
+const TOKEN_TYPE_SEPARATOR: char = ':';
+
+pub type TokenTypeId = String;
+
+//a series of tokens that share the same metadata template
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct TokenType {
+    //metadata every token of the type is minted with
+    pub metadata: TokenMetadata,
+    //game attributes every token of the type is minted with
+    pub attributes: Option<TokenAttributes>,
+    //maximum number of tokens that can ever be minted for the type
+    pub max_copies: u64,
+    //price in yoctoNEAR to mint one token of the type
+    pub price: Balance,
+    //number of tokens minted so far. This is also the last edition number given out.
+    pub minted: u64,
+    //royalty percentages every token of the type is minted with
+    pub royalty: HashMap<AccountId, u32>,
+    //whether the tokens of the type are bound to the account holding them
+    pub soulbound: bool,
+    //time in milliseconds tokens of the type are valid for after they are minted
+    pub valid_for: Option<u64>,
+}
+
+//The Json token type is what will be returned from view calls.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonTokenType {
+    //token type ID
+    pub token_type_id: TokenTypeId,
+    //metadata template for the type
+    pub metadata: TokenMetadata,
+    //attributes template for the type
+    pub attributes: Option<TokenAttributes>,
+    //maximum number of tokens that can be minted
+    pub max_copies: u64,
+    //price to mint one token
+    pub price: U128,
+    //number of tokens minted so far
+    pub minted: u64,
+    //royalty percentages for the tokens of the type
+    pub royalty: HashMap<AccountId, u32>,
+    //whether the tokens of the type can't be transferred
+    pub soulbound: bool,
+    //time in milliseconds tokens of the type are valid for after they are minted
+    pub valid_for: Option<u64>,
+    //whether minting is locked for the type
+    pub locked: bool,
+}
+
+#[near_bindgen]
+impl Contract {
+    //create a new token type. The owner needs to attach enough to cover the storage.
+    #[payable]
+    pub fn create_token_type(
+        &mut self,
+        token_type_id: TokenTypeId,
+        metadata: TokenMetadata,
+        attributes: Option<TokenAttributes>,
+        max_copies: u64,
+        price: U128,
+        //we add an optional parameter for perpetual royalties. The collection royalty is used if none are passed in.
+        royalty: Option<HashMap<AccountId, u32>>,
+    ) {
+        self.assert_owner();
+        assert!(
+            !token_type_id.is_empty() && token_type_id.len() <= MAX_TOKEN_TYPE_ID_LEN,
+            "Token type ID must be between 1 and {} bytes",
+            MAX_TOKEN_TYPE_ID_LEN
+        );
+        assert!(
+            !token_type_id.contains(TOKEN_TYPE_SEPARATOR),
+            "Token type ID cannot contain '{}'",
+            TOKEN_TYPE_SEPARATOR
+        );
+        assert!(max_copies > 0, "Max copies must be greater than 0");
+
+        let royalty = royalty.unwrap_or_else(default_royalty);
+        //make sure that the length of the perpetual royalties is below 7 since we won't have enough GAS to pay out that many people
+        assert!(royalty.len() < 7, "Cannot add more than 6 perpetual royalty amounts");
+        //the royalties can't add up to more than the price of a sale
+        assert!(royalty.values().sum::<u32>() <= 10000, "Perpetual royalties cannot add up to more than 100%");
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let token_type = TokenType {
+            metadata,
+            attributes,
+            max_copies,
+            price: price.into(),
+            minted: 0,
+            royalty,
+            //types can be made soulbound with set_token_type_soulbound
+            soulbound: false,
+            //the validity of the tokens can be limited with set_token_type_validity
+            valid_for: None,
+        };
+        //insert the token type and make sure that it doesn't exist
+        assert!(
+            self.token_types_by_id.insert(&token_type_id, &token_type).is_none(),
+            "Token type already exists"
+        );
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess storage if the owner attached too much. Panic if they didn't attach enough to cover the required.
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    //lock a token type so that no more tokens can be minted for it
+    pub fn lock_token_type(&mut self, token_type_id: TokenTypeId) {
+        self.assert_owner();
+        assert!(self.token_types_by_id.get(&token_type_id).is_some(), "No token type");
+        self.token_types_locked.insert(&token_type_id);
+    }
+
+    /*
+        mint the next edition of a token type. The owner can mint for free,
+        everyone else needs to attach the price of the type plus the storage for the token.
+    */
+    #[payable]
+    pub fn nft_mint_type(&mut self, token_type_id: TokenTypeId, receiver_id: Option<AccountId>) -> TokenId {
+        let account_id = env::predecessor_account_id();
+        //if no receiver was passed in, the token is minted to the caller
+        let receiver_id = receiver_id.unwrap_or_else(|| account_id.clone());
+
+        //the owner doesn't pay for the tokens it mints
+        let price = if account_id == self.owner_id {
+            0
+        } else {
+            self.token_types_by_id.get(&token_type_id).expect("No token type").price
+        };
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let token_id = self.internal_mint_typed(&token_type_id, &receiver_id, None);
+
+        //calculate the required storage which was the used - initial
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        //refund any excess if the user attached too much. Panic if they didn't attach enough to cover the price and storage.
+        refund_deposit_with_price(required_storage_in_bytes, price);
+
+        //the proceeds go to the contract owner
+        if price > 0 {
+            Promise::new(self.owner_id.clone()).transfer(price);
+        }
+
+        token_id
+    }
+
+    //get the information for a specific token type
+    pub fn nft_token_type(&self, token_type_id: TokenTypeId) -> Option<JsonTokenType> {
+        self.token_types_by_id
+            .get(&token_type_id)
+            .map(|token_type| self.internal_json_token_type(token_type_id, token_type))
+    }
+
+    //Query for token types on the contract using pagination
+    pub fn nft_token_types(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonTokenType> {
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        self.token_types_by_id.iter()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements. If we didn't specify a limit, use 0
+            .take(limit.unwrap_or(0) as usize)
+            //we'll map the token types into Json token types
+            .map(|(token_type_id, token_type)| self.internal_json_token_type(token_type_id, token_type))
+            .collect()
+    }
+
+    //get the number of tokens in existence for a given token type
+    pub fn nft_supply_for_type(&self, token_type_id: TokenTypeId) -> U128 {
+        //if there is some set of tokens, we'll return the length as a U128
+        if let Some(tokens_for_type_set) = self.tokens_per_type.get(&token_type_id) {
+            U128(tokens_for_type_set.len() as u128)
+        } else {
+            //if there isn't a set of tokens for the passed in type, we'll return 0
+            U128(0)
+        }
+    }
+
+    //Query for all the tokens of a given token type
+    pub fn nft_tokens_for_type(
+        &self,
+        token_type_id: TokenTypeId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<JsonToken> {
+        //if there is no set of tokens, we'll simply return an empty vector.
+        let tokens = if let Some(tokens_for_type_set) = self.tokens_per_type.get(&token_type_id) {
+            tokens_for_type_set
+        } else {
+            return vec![];
+        };
+
+        //where to start pagination - if we have a from_index, we'll use that - otherwise start from 0 index
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        tokens.as_vector().iter()
+            //skip to the index we specified in the start variable
+            .skip(start as usize)
+            //take the first "limit" elements in the vector. If we didn't specify a limit, use 0
+            .take(limit.unwrap_or(0) as usize)
+            //we'll map the token IDs which are strings into Json Tokens
+            .map(|token_id| self.nft_token(token_id).unwrap())
+            .collect()
+    }
+
+    //check if minting is locked for a token type
+    pub fn is_token_type_locked(&self, token_type_id: TokenTypeId) -> bool {
+        self.token_types_locked.contains(&token_type_id)
+    }
+}
+
+impl Contract {
+    //mint the next edition of a token type to the receiver and return the new token ID
+    //the attributes of the type template are used unless other attributes are passed in
+    pub(crate) fn internal_mint_typed(
+        &mut self,
+        token_type_id: &TokenTypeId,
+        receiver_id: &AccountId,
+        attributes: Option<TokenAttributes>,
+    ) -> TokenId {
+        let mut token_type = self.token_types_by_id.get(token_type_id).expect("No token type");
+        assert!(!self.token_types_locked.contains(token_type_id), "Token type is locked");
+        assert!(token_type.minted < token_type.max_copies, "All copies of the token type have been minted");
+
+        //editions start at 1
+        token_type.minted += 1;
+        let token_id = format!("{}{}{}", token_type_id, TOKEN_TYPE_SEPARATOR, token_type.minted);
+
+        //specify the token struct that contains the owner ID
+        let token = Token {
+            owner_id: receiver_id.clone(),
+            approved_account_ids: Default::default(),
+            next_approval_id: 0,
+            royalty: token_type.royalty.clone(),
+        };
+
+        //insert the token ID and token struct and make sure that the token doesn't exist
+        assert!(
+            self.tokens_by_id.insert(&token_id, &token).is_none(),
+            "Token already exists"
+        );
+
+        //build the metadata from the type template
+        let mut metadata = token_type.metadata.clone();
+        if let Some(title) = metadata.title {
+            metadata.title = Some(format!("{} #{}", title, token_type.minted));
+        }
+        metadata.copies = Some(token_type.max_copies);
+        metadata.issued_at = Some(env::block_timestamp() / 1000000);
+        metadata.updated_at = None;
+        //tokens of types with a validity period expire that long after they are minted
+        if let Some(valid_for) = token_type.valid_for {
+            metadata.expires_at = Some(env::block_timestamp() / 1000000 + valid_for);
+        }
+        //keep track of the tokens that can expire so they can be purged
+        if metadata.expires_at.is_some() {
+            self.expiring_tokens.insert(&token_id);
+        }
+        //use the hashes for the token if the owner already uploaded them
+        if let Some(token_hashes) = self.token_hashes_by_id.get(&token_id) {
+            metadata.media_hash = Some(token_hashes.media_hash);
+            metadata.reference_hash = Some(token_hashes.reference_hash);
+        }
+        //the extra field holds the JSON version of the attributes
+        if let Some(attributes) = attributes.as_ref().or(token_type.attributes.as_ref()) {
+            assert_valid_attributes(attributes);
+            metadata.extra = Some(near_sdk::serde_json::to_string(attributes).unwrap());
+            self.attributes_by_id.insert(&token_id, attributes);
+        }
+        self.token_metadata_by_id.insert(&token_id, &metadata);
+
+        //call the internal method for adding the token to the owner and the type
+        self.internal_add_token_to_owner(receiver_id, &token_id);
+        self.internal_add_token_to_type(token_type_id, &token_id);
+        self.token_types_by_id.insert(token_type_id, &token_type);
+
+        self.internal_log_mint(receiver_id, vec![token_id.clone()]);
+
+        token_id
+    }
+
+    //remove a token from the set of tokens of a token type
+    pub(crate) fn internal_remove_token_from_type(&mut self, token_type_id: &TokenTypeId, token_id: &TokenId) {
+        if let Some(mut tokens_set) = self.tokens_per_type.get(token_type_id) {
+            tokens_set.remove(token_id);
+
+            //if the token set is now empty, we remove the type from the tokens_per_type collection
+            if tokens_set.is_empty() {
+                self.tokens_per_type.remove(token_type_id);
+            } else {
+                self.tokens_per_type.insert(token_type_id, &tokens_set);
+            }
+        }
+    }
+
+    //add a token to the set of tokens of a token type
+    pub(crate) fn internal_add_token_to_type(&mut self, token_type_id: &TokenTypeId, token_id: &TokenId) {
+        //get the set of tokens for the given type
+        let mut tokens_set = self.tokens_per_type.get(token_type_id).unwrap_or_else(|| {
+            //if the type doesn't have any tokens, we create a new unordered set
+            UnorderedSet::new(
+                StorageKey::TokensPerTypeInner {
+                    //we get a new unique prefix for the collection
+                    token_type_hash: hash_token_type_id(token_type_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+
+        tokens_set.insert(token_id);
+        self.tokens_per_type.insert(token_type_id, &tokens_set);
+    }
+
+    //convert a token type into the JSON version returned by view calls
+    fn internal_json_token_type(&self, token_type_id: TokenTypeId, token_type: TokenType) -> JsonTokenType {
+        JsonTokenType {
+            locked: self.token_types_locked.contains(&token_type_id),
+            token_type_id,
+            metadata: token_type.metadata,
+            attributes: token_type.attributes,
+            max_copies: token_type.max_copies,
+            price: U128(token_type.price),
+            minted: token_type.minted,
+            royalty: token_type.royalty,
+            soulbound: token_type.soulbound,
+            valid_for: token_type.valid_for,
+        }
+    }
+}
+
+//get the token type ID of a token minted as `<type>:<edition>`. Returns None for tokens without a type.
+pub(crate) fn token_type_of(token_id: &str) -> Option<TokenTypeId> {
+    token_id
+        .split_once(TOKEN_TYPE_SEPARATOR)
+        .map(|(token_type_id, _)| token_type_id.to_string())
+}
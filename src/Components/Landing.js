@@ -6,6 +6,10 @@ import Countdown from 'react-countdown';
 import 'react-notifications/lib/notifications.css';
 import { WalletContext, NFT_CONTRACT_ID, MAX_GAS } from "../contexts/wallet";
 import "./style.css";
+
+// NEAR attached to free mints to cover the storage of the token
+const MINT_STORAGE_DEPOSIT = "0.05";
+
 function Landing() {
   const [isloading, setLoading] = React.useState(false);
 
@@ -67,11 +71,12 @@ function Landing() {
 
     const contributor_0 = await wallet.account().viewFunction(NFT_CONTRACT_ID, "get_contributor_0");
 
-    let mint_price = "0";
+    // free mints attach enough to cover the storage of the token, the excess is refunded
+    let mint_price = MINT_STORAGE_DEPOSIT;
     if (nftSupply && nftSupply > freelimit) {
       mint_price = "5";
       if (contributor_0.includes(wallet.getAccountId())) {
-        mint_price = "0";
+        mint_price = MINT_STORAGE_DEPOSIT;
       }
     }
 